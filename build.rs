@@ -1,8 +1,56 @@
-use std::path::Path;
+use std::{env, fmt::Write, fs, path::Path};
 
 fn main() {
-    if Path::new("input").is_dir() {
-        println!("cargo:rustc-cfg=input_exists");
+    // `input` is checked in with just a `.gitignore`, so it's always there to watch, and cargo
+    // watches everything inside it.
+    let input_dir = Path::new("input");
+    println!("cargo::rerun-if-changed=input");
+
+    let mut inputs: Vec<(u16, u8)> = vec![];
+    for entry in fs::read_dir(input_dir).into_iter().flatten().flatten() {
+        let Some(year) = entry.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        inputs.extend(
+            (1..=25)
+                .filter(|day| entry.path().join(format!("day{day}.txt")).is_file())
+                .map(|day| (year, day)),
+        );
+    }
+    inputs.sort_unstable();
+
+    // `input_exists` is set if there are any inputs at all, and `input_exists = "<year>/day<day>"`
+    // for each day whose input is present.
+    if !inputs.is_empty() {
+        println!("cargo::rustc-cfg=input_exists");
+    }
+    for (year, day) in &inputs {
+        println!("cargo::rustc-cfg=input_exists=\"{year}/day{day}\"");
     }
     println!("cargo::rustc-check-cfg=cfg(input_exists)");
+    println!("cargo::rustc-check-cfg=cfg(input_exists, values(any()))");
+
+    // The `embedded_input` function the runner uses to look up the inputs it embeds when it's
+    // built without `io`. With no inputs it ignores `year` and `day` and always gives `None`.
+    let embedded = if inputs.is_empty() {
+        "fn embedded_input(_year: u16, _day: u8) -> Option<&'static str> {\n    None\n}\n"
+            .to_owned()
+    } else {
+        let mut embedded = String::from(
+            "fn embedded_input(year: u16, day: u8) -> Option<&'static str> {\n    match (year, day) {\n",
+        );
+        for (year, day) in &inputs {
+            let path = fs::canonicalize(input_dir.join(format!("{year}/day{day}.txt"))).unwrap();
+            writeln!(
+                embedded,
+                "        ({year}, {day}) => Some(include_str!({path:?})),"
+            )
+            .unwrap();
+        }
+        embedded += "        _ => None,\n    }\n}\n";
+        embedded
+    };
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("embedded_inputs.rs"), embedded).unwrap();
 }
@@ -0,0 +1,193 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where the known answers for `year` are kept. Unlike the inputs, these are checked in.
+pub fn answers_file(year: u16) -> PathBuf {
+    PathBuf::from(format!("answers/{year}.txt"))
+}
+
+/// The known answer for each day and part of one year.
+///
+/// The file format is one `<day>-<part>: <answer>` entry per line, matching the runner's output.
+/// Blank lines and lines starting with `#` are ignored, and kept as they are when new answers are
+/// added to the end of the file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+    /// The file as it was loaded, plus a line for each answer inserted since.
+    text: String,
+}
+
+/// The result of checking an answer against the known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "ok",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "OK"),
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || format!("invalid answer on line {}: '{line}'", idx + 1);
+            let (key, answer) = line.split_once(':').ok_or_else(error)?;
+            let (day, part) = key.split_once('-').ok_or_else(error)?;
+            let day = day.trim().parse().map_err(|_| error())?;
+            let part = part.trim().parse().map_err(|_| error())?;
+            let answer = answer.trim();
+            if !matches!(part, 1 | 2) || answer.is_empty() {
+                return Err(error());
+            }
+            answers.insert((day, part), answer.to_owned());
+        }
+
+        let mut text = s.to_owned();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        Ok(Self { answers, text })
+    }
+
+    /// Loads the answers, treating a missing file as having no known answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the file as it was loaded with the inserted answers at the end.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, &self.text)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Adds the answer for a part that doesn't have one yet.
+    ///
+    /// # Panics
+    ///
+    /// If there's already an answer for `day` and `part`.
+    pub fn insert(&mut self, day: u8, part: u8, answer: impl fmt::Display) {
+        let answer = answer.to_string();
+        writeln!(self.text, "{day}-{part}: {answer}").unwrap();
+        let old = self.answers.insert((day, part), answer);
+        assert!(old.is_none(), "day {day}-{part} already has an answer");
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: impl fmt::Display) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Runs both parts of a day on `input` and checks them against the answers file, which must have
+/// an answer for both of them.
+#[cfg(all(test, input_exists))]
+pub(crate) fn assert_my_answers<S: crate::Solution>(year: u16, input: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(answers_file(year));
+    let answers = Answers::load(&path).unwrap();
+    let input = S::parse(input).unwrap();
+    for (part, answer) in [
+        (1, S::part_1(&input).to_string()),
+        (2, S::part_2(&input).to_string()),
+    ] {
+        match answers.check(S::DAY, part, &answer) {
+            Verdict::Correct => {}
+            Verdict::Wrong { expected } => {
+                panic!("day {}-{part}: got {answer}, expected {expected}", S::DAY)
+            }
+            Verdict::Unknown => panic!(
+                "day {}-{part}: no answer in {}, record it with `bin --record`",
+                S::DAY,
+                path.display()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut answers = Answers::parse("# day 1\n1-1: 1018\n\n1-2: 5815\n").unwrap();
+        assert_eq!(answers.get(1, 2), Some("5815"));
+        assert_eq!(answers.check(1, 1, 1018), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 2, 5816),
+            Verdict::Wrong {
+                expected: "5815".to_owned()
+            }
+        );
+        assert_eq!(answers.check(2, 1, 1), Verdict::Unknown);
+
+        answers.insert(2, 1, 53420042388u64);
+        assert_eq!(answers.check(2, 1, 53420042388u64), Verdict::Correct);
+        let path =
+            std::env::temp_dir().join(format!("aoc-answers-test-{}.txt", std::process::id()));
+        answers.save(&path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# day 1\n1-1: 1018\n\n1-2: 5815\n2-1: 53420042388\n"
+        );
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+        fs::remove_file(&path).unwrap();
+
+        let mut answers = Answers::parse("1-1: 1018").unwrap();
+        answers.insert(1, 2, 5815);
+        assert_eq!(answers.to_string(), "1-1: 1018\n1-2: 5815\n");
+
+        assert!(Answers::parse("1-3: 5").is_err());
+        assert!(Answers::parse("1: 5").is_err());
+        assert!(Answers::parse("1-1:").is_err());
+    }
+}
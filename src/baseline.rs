@@ -0,0 +1,146 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use crate::bench::Phase;
+
+/// Saved timings for each year, day and phase, used to spot performance regressions between runs.
+///
+/// The file format is one `<year> <day> <phase> <nanoseconds>` entry per line.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Baseline {
+    timings: BTreeMap<(u16, u8, Phase), Duration>,
+}
+
+/// A timing measured in this run, next to the one from the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// The relative change in time, e.g. `0.1` for 10% slower or `-0.5` for twice as fast.
+    pub fn change(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+impl Baseline {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut timings = BTreeMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let error = || format!("invalid baseline entry on line {}: '{line}'", idx + 1);
+            let mut fields = line.split_ascii_whitespace();
+            let (Some(year), Some(day), Some(phase), Some(nanos), None) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                return Err(error());
+            };
+
+            let year = year.parse().map_err(|_| error())?;
+            let day = day.parse().map_err(|_| error())?;
+            let phase = Phase::from_name(phase).ok_or_else(error)?;
+            let nanos = nanos.parse().map_err(|_| error())?;
+            timings.insert((year, day, phase), Duration::from_nanos(nanos));
+        }
+
+        Ok(Self { timings })
+    }
+
+    /// Loads a baseline, treating a missing file as an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Whether there are any timings for `year`.
+    pub fn has_year(&self, year: u16) -> bool {
+        self.timings.keys().any(|&(y, _, _)| y == year)
+    }
+
+    pub fn get(&self, year: u16, day: u8, phase: Phase) -> Option<Duration> {
+        self.timings.get(&(year, day, phase)).copied()
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, phase: Phase, time: Duration) {
+        self.timings.insert((year, day, phase), time);
+    }
+
+    pub fn compare(
+        &self,
+        year: u16,
+        day: u8,
+        phase: Phase,
+        current: Duration,
+    ) -> Option<Comparison> {
+        self.get(year, day, phase)
+            .map(|baseline| Comparison { baseline, current })
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, phase), time) in &self.timings {
+            writeln!(f, "{year} {day} {} {}", phase.name(), time.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut baseline =
+            Baseline::parse("2025 7 part1 2000\n2025 7 parse 1000\n\n2024 7 part1 9\n").unwrap();
+        assert_eq!(
+            baseline.get(2025, 7, Phase::Parse),
+            Some(Duration::from_micros(1))
+        );
+        assert_eq!(baseline.get(2025, 7, Phase::Part2), None);
+        assert_eq!(baseline.get(2024, 7, Phase::Parse), None);
+        assert!(baseline.has_year(2024) && !baseline.has_year(2026));
+
+        baseline.insert(2025, 3, Phase::Part2, Duration::from_nanos(5));
+        assert_eq!(
+            baseline.to_string(),
+            "2024 7 part1 9\n2025 3 part2 5\n2025 7 parse 1000\n2025 7 part1 2000\n"
+        );
+        assert_eq!(Baseline::parse(&baseline.to_string()), Ok(baseline.clone()));
+
+        let comparison = baseline
+            .compare(2025, 7, Phase::Part1, Duration::from_nanos(2500))
+            .unwrap();
+        assert!((comparison.change() - 0.25).abs() < 1e-9);
+        assert!(comparison.is_regression(0.1));
+        assert!(!comparison.is_regression(0.3));
+
+        assert!(Baseline::parse("2025 7 part3 1").is_err());
+        assert!(Baseline::parse("2025 7 part1").is_err());
+        assert!(Baseline::parse("7 part1 2000").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// The separately timed phases of running a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    /// A short name without spaces, used in saved baselines and structured output.
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|phase| phase.name() == name)
+    }
+}
+
+/// How long to spend warming up and sampling each measured phase.
+pub struct Config {
+    pub warm_up: Duration,
+    pub measure: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+    /// Very fast phases are run in batches of at least this long, so that the overhead of reading
+    /// the clock doesn't dominate each sample.
+    pub min_sample_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(100),
+            measure: Duration::from_millis(500),
+            min_samples: 10,
+            max_samples: 100_000,
+            min_sample_time: Duration::from_micros(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            samples: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// Bytes processed per second, based on the median time.
+    pub fn throughput(&self, bytes: usize) -> f64 {
+        bytes as f64 / self.median.as_secs_f64()
+    }
+}
+
+/// Repeatedly times `f` after a warm-up period.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut warm_up_iters = 0u32;
+    while warm_up_iters == 0 || start.elapsed() < config.warm_up {
+        black_box(f());
+        warm_up_iters += 1;
+    }
+
+    let per_iter = start.elapsed() / warm_up_iters;
+    let batch = if per_iter.is_zero() {
+        1000
+    } else {
+        (config.min_sample_time.as_nanos() / per_iter.as_nanos()).clamp(1, 1000) as u32
+    };
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < config.min_samples
+        || (samples.len() < config.max_samples && start.elapsed() < config.measure)
+    {
+        let sample_start = Instant::now();
+        for _ in 0..batch {
+            black_box(f());
+        }
+        samples.push(sample_start.elapsed() / batch);
+    }
+
+    Stats::from_samples(&mut samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut samples = [4, 1, 100, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean.as_millis(), 22);
+        assert_eq!(stats.stddev.as_micros(), 43617);
+        assert_eq!(stats.throughput(3000), 1_000_000.0);
+
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.median, Duration::from_micros(2500));
+
+        let config = Config {
+            warm_up: Duration::ZERO,
+            measure: Duration::ZERO,
+            ..Config::default()
+        };
+        assert_eq!(measure(&config, || 1 + 1).samples, config.min_samples);
+    }
+}
@@ -1,66 +1,711 @@
-use std::time::Instant;
+use std::{
+    borrow::Cow,
+    io::Read,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
 
-use advent_of_code_2025::*;
+use advent_of_code_2025::{
+    answers::{Answers, Verdict, answers_file},
+    baseline::Baseline,
+    bench::{Phase, Stats},
+    *,
+};
 
+fn read_file(path: &Path) -> Result<Cow<'static, str>, String> {
+    std::fs::read_to_string(path)
+        .map(Cow::Owned)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))
+}
+
+/// The input for `day` when no other input was given, or `None` if it isn't available.
 #[cfg(feature = "io")]
-macro_rules! input_str {
-    ($d:expr) => {
-        std::fs::read_to_string(concat!("input/2025/day", $d, ".txt")).unwrap()
-    };
+fn default_input(year: u16, day: u8) -> Result<Option<Cow<'static, str>>, String> {
+    inputs::InputCache::from_env(Path::new("."))
+        .get(year, day)
+        .map(|input| Some(Cow::Owned(input)))
+        .map_err(|e| format!("failed to get the input: {e}"))
 }
 
 #[cfg(not(feature = "io"))]
-macro_rules! input_str {
-    ($d:expr) => {
-        include_str!(concat!("../../input/2025/day", $d, ".txt"))
-    };
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// The input for `day` when no other input was given, or `None` if it isn't available.
+#[cfg(not(feature = "io"))]
+fn default_input(year: u16, day: u8) -> Result<Option<Cow<'static, str>>, String> {
+    Ok(embedded_input(year, day).map(Cow::Borrowed))
+}
+
+/// The answers and timings from running a single day.
+struct Report {
+    day: u8,
+    title: &'static str,
+    answers: Vec<PartAnswer>,
+    timings: Vec<(Phase, Stats)>,
+}
+
+struct PartAnswer {
+    part: u8,
+    answer: String,
+    /// Only set when checking answers.
+    verdict: Option<Verdict>,
+}
+
+impl PartAnswer {
+    fn new(part: u8, answer: String) -> Self {
+        Self {
+            part,
+            answer,
+            verdict: None,
+        }
+    }
+}
+
+fn single_sample(time: Duration) -> Stats {
+    Stats::from_samples(&mut [time])
 }
 
-macro_rules! run_parts {
-    ($m:ident, $d:expr, $g:expr) => {
+fn run_day(day: &dyn DynSolution, input: &str, part: Option<u8>) -> Result<Report, ParseError> {
+    let mut answers = vec![];
+    let mut timings = vec![];
+
+    let instant = Instant::now();
+    let processed_input = day.parse(input)?;
+    timings.push((Phase::Parse, single_sample(instant.elapsed())));
+    if part != Some(2) {
         let instant = Instant::now();
-        let input = input_str!($d);
-        let processed_input = $g(&input);
-        let parse_time = instant.elapsed();
-        let sol1 = $m::part_1(&processed_input);
-        let sol1_time = instant.elapsed();
-        let sol2 = $m::part_2(&processed_input);
-        let sol2_time = instant.elapsed();
-        println!("day {0}-1: {1}\nday {0}-2: {2}", $d, sol1, sol2,);
-
-        println!(
-            "{:?} (parse: {:?}, 1: {:?}, 2: {:?})\n",
-            sol2_time,
-            parse_time,
-            sol1_time - parse_time,
-            sol2_time - sol1_time
-        );
-    };
+        answers.push(PartAnswer::new(1, processed_input.part_1()));
+        timings.push((Phase::Part1, single_sample(instant.elapsed())));
+    }
+    if part != Some(1) {
+        let instant = Instant::now();
+        answers.push(PartAnswer::new(2, processed_input.part_2()));
+        timings.push((Phase::Part2, single_sample(instant.elapsed())));
+    }
+
+    Ok(Report {
+        day: day.day(),
+        title: day.title(),
+        answers,
+        timings,
+    })
 }
 
-macro_rules! run_day_with_generator {
-    ($m:ident, $d:expr) => {
-        run_parts!($m, $d, |i| $m::input_generator(i));
-    };
+fn bench_day(
+    day: &dyn DynSolution,
+    input: &str,
+    part: Option<u8>,
+    config: &bench::Config,
+) -> Result<Report, ParseError> {
+    let mut answers = vec![];
+    let mut timings = vec![];
+
+    let processed_input = day.parse(input)?;
+    timings.push((Phase::Parse, bench::measure(config, || day.parse(input))));
+    if part != Some(2) {
+        answers.push(PartAnswer::new(1, processed_input.part_1()));
+        let stats = bench::measure(config, || processed_input.part_1());
+        timings.push((Phase::Part1, stats));
+    }
+    if part != Some(1) {
+        answers.push(PartAnswer::new(2, processed_input.part_2()));
+        let stats = bench::measure(config, || processed_input.part_2());
+        timings.push((Phase::Part2, stats));
+    }
+
+    Ok(Report {
+        day: day.day(),
+        title: day.title(),
+        answers,
+        timings,
+    })
 }
 
-macro_rules! run_day {
-    ($m:ident, $d:expr) => {
-        run_parts!($m, $d, |i| i);
-    };
+fn format_throughput(bytes_per_sec: f64) -> String {
+    let units = ["B/s", "KB/s", "MB/s", "GB/s", "TB/s"];
+    let mut value = bytes_per_sec;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < units.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", units[unit])
+}
+
+fn print_report(report: &Report, input_len: usize, bench: bool) {
+    let d = report.day;
+    if bench {
+        println!("day {d}: {} ({input_len} bytes of input)", report.title);
+    }
+    for PartAnswer {
+        part,
+        answer,
+        verdict,
+    } in &report.answers
+    {
+        match verdict {
+            Some(verdict) => println!("day {d}-{part}: {answer} ({verdict})"),
+            None => println!("day {d}-{part}: {answer}"),
+        }
+    }
+
+    if bench {
+        for (phase, stats) in &report.timings {
+            println!(
+                "  {:<6}  min {:>10.2?}  median {:>10.2?}  mean {:>10.2?} ± {:<10.2?} {:>12}  ({} samples)",
+                phase.name(),
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                format_throughput(stats.throughput(input_len)),
+                stats.samples,
+            );
+        }
+    } else {
+        let total: Duration = report.timings.iter().map(|(_, stats)| stats.median).sum();
+        let phases: Vec<_> = report
+            .timings
+            .iter()
+            .map(|(phase, stats)| format!("{}: {:?}", phase.name(), stats.median))
+            .collect();
+        println!("{total:?} ({})", phases.join(", "));
+    }
+}
+
+/// Describes how the timings compare with the baseline, also returning the number of
+/// regressions.
+fn compare_report(
+    year: u16,
+    report: &Report,
+    baseline: &Baseline,
+    threshold: f64,
+) -> (Vec<String>, usize) {
+    let mut lines = vec![];
+    let mut regressions = 0;
+    for (phase, stats) in &report.timings {
+        let Some(comparison) = baseline.compare(year, report.day, *phase, stats.median) else {
+            lines.push(format!("  {:<6}  no baseline", phase.name()));
+            continue;
+        };
+
+        let regressed = comparison.is_regression(threshold);
+        regressions += regressed as usize;
+        lines.push(format!(
+            "  {:<6}  {:.2?} vs {:.2?} ({:+.1}%){}",
+            phase.name(),
+            comparison.current,
+            comparison.baseline,
+            100.0 * comparison.change(),
+            if regressed { "  REGRESSION" } else { "" },
+        ));
+    }
+    (lines, regressions)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
 }
 
-pub fn main() {
+/// Prints one record per part run, with the answer and the parse and solve times in nanoseconds.
+fn print_records(year: u16, reports: &[Report], format: Format) {
+    let records = reports.iter().flat_map(|report| {
+        let time = |phase| {
+            report
+                .timings
+                .iter()
+                .find(|(p, _)| *p == phase)
+                .map_or(0, |(_, stats)| stats.median.as_nanos())
+        };
+        let parse_ns = time(Phase::Parse);
+        report.answers.iter().map(move |answer| {
+            let solve_ns = time(if answer.part == 1 {
+                Phase::Part1
+            } else {
+                Phase::Part2
+            });
+            (report, answer, parse_ns, solve_ns)
+        })
+    });
+
+    match format {
+        Format::Text => unreachable!("text output is printed as each day runs"),
+        Format::Json => {
+            let records: Vec<_> = records
+                .map(|(report, answer, parse_ns, solve_ns)| {
+                    let status = answer
+                        .verdict
+                        .as_ref()
+                        .map_or("null".to_owned(), |v| json_string(v.name()));
+                    format!(
+                        "  {{\"year\": {year}, \"day\": {}, \"title\": {}, \"part\": {}, \"answer\": {}, \"status\": {status}, \"parse_ns\": {parse_ns}, \"solve_ns\": {solve_ns}}}",
+                        report.day,
+                        json_string(report.title),
+                        answer.part,
+                        json_string(&answer.answer),
+                    )
+                })
+                .collect();
+            println!("[\n{}\n]", records.join(",\n"));
+        }
+        Format::Csv => {
+            println!("year,day,title,part,answer,status,parse_ns,solve_ns");
+            for (report, answer, parse_ns, solve_ns) in records {
+                println!(
+                    "{year},{},{},{},{},{},{parse_ns},{solve_ns}",
+                    report.day,
+                    csv_field(report.title),
+                    answer.part,
+                    csv_field(&answer.answer),
+                    answer.verdict.as_ref().map_or("", Verdict::name),
+                );
+            }
+        }
+    }
+}
+
+const USAGE: &str = "\
+Usage: bin [DAYS...] [OPTIONS]
+
+Runs the selected days of one year, or every day if none are given.
+
+DAYS may be a single day (7), a range (3..6) or an inclusive range (3..=5).
+
+Options:
+  -y, --year <YEAR>            the year to run (default: the latest)
+  -p, --part <1|2>             only run the given part
+  -i, --input <[DAY=]FILE>     read the input for DAY from FILE, or from stdin if FILE is '-';
+                               DAY may be omitted when a single day is selected
+      --input-dir <DIR>        read inputs from DIR/dayN.txt instead of input/YEAR/dayN.txt
+  -c, --check                  check the answers against the answers file
+      --record                 check the answers, and record any that weren't known yet
+      --answers <FILE|none>    the answers file, or none to not check against one (default:
+                               answers/YEAR.txt, DIR/answers.txt with --input-dir, or none
+                               with --input)
+  -f, --format <FORMAT>        output format: text, json or csv (default: text)
+  -b, --bench                  time each phase repeatedly and report statistics
+      --save-baseline          save the timings to the baseline file
+      --compare                compare the timings with the baseline file, failing if any phase
+                               regressed
+      --baseline <FILE>        the baseline file (default: target/aoc-baseline-YEAR.txt)
+      --threshold <PERCENT>    how much slower a phase may get before it counts as a regression
+                               (default: 10)
+  -h, --help                   print this message";
+
+struct Args {
+    year: &'static Year,
+    days: Vec<u8>,
+    part: Option<u8>,
+    inputs: Vec<(u8, PathBuf)>,
+    input_dir: Option<PathBuf>,
+    format: Format,
+    check: bool,
+    record: bool,
+    /// `None` when there's nothing to check the answers against.
+    answers: Option<PathBuf>,
+    bench: bool,
+    save_baseline: bool,
+    compare: bool,
+    baseline: PathBuf,
+    threshold: f64,
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse().map_err(|_| format!("invalid day: '{s}'"))
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    if let Some((start, end)) = s.split_once("..=") {
+        Ok(parse_day(start)?..=parse_day(end)?)
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = parse_day(end)?;
+        if end == 0 {
+            return Err(format!("empty day range: '{s}'"));
+        }
+        Ok(parse_day(start)?..=end - 1)
+    } else {
+        let day = parse_day(s)?;
+        Ok(day..=day)
+    }
+}
+
+impl Args {
+    /// Returns `Ok(None)` if the usage message was requested.
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut year = latest_year();
+        let mut ranges = vec![];
+        let mut part = None;
+        let mut inputs = vec![];
+        let mut any_day_inputs = vec![];
+        let mut input_dir = None;
+        let mut format = Format::Text;
+        let mut check = false;
+        let mut record = false;
+        let mut answers = None;
+        let mut bench = false;
+        let mut save_baseline = false;
+        let mut compare = false;
+        let mut baseline = None;
+        let mut threshold = 10.0;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-y" | "--year" => {
+                    let value = args.next().ok_or("missing value for --year")?;
+                    year = value.parse().ok().and_then(find_year).ok_or_else(|| {
+                        let available: Vec<_> = YEARS.iter().map(|y| y.year.to_string()).collect();
+                        format!(
+                            "unknown year: '{value}' (available: {})",
+                            available.join(", ")
+                        )
+                    })?;
+                }
+                "-b" | "--bench" => bench = true,
+                "-c" | "--check" => check = true,
+                "--record" => record = true,
+                "--answers" => {
+                    let value = args.next().ok_or("missing value for --answers")?;
+                    answers = Some(PathBuf::from(value));
+                }
+                "-f" | "--format" => {
+                    let value = args.next().ok_or("missing value for --format")?;
+                    format = match value.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        "csv" => Format::Csv,
+                        _ => return Err(format!("invalid format: '{value}'")),
+                    };
+                }
+                "--save-baseline" => save_baseline = true,
+                "--compare" => compare = true,
+                "--baseline" => {
+                    let value = args.next().ok_or("missing value for --baseline")?;
+                    baseline = Some(PathBuf::from(value));
+                }
+                "--threshold" => {
+                    let value = args.next().ok_or("missing value for --threshold")?;
+                    threshold = value
+                        .parse()
+                        .ok()
+                        .filter(|t: &f64| *t >= 0.0)
+                        .ok_or_else(|| format!("invalid threshold: '{value}'"))?;
+                }
+                "-p" | "--part" => {
+                    let value = args.next().ok_or("missing value for --part")?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("invalid part: '{value}' (expected 1 or 2)")),
+                    };
+                }
+                "-i" | "--input" => {
+                    let value = args.next().ok_or("missing value for --input")?;
+                    match value.split_once('=') {
+                        Some((day, path)) => inputs.push((parse_day(day)?, PathBuf::from(path))),
+                        None => any_day_inputs.push(PathBuf::from(value)),
+                    }
+                }
+                "--input-dir" => {
+                    let value = args.next().ok_or("missing value for --input-dir")?;
+                    input_dir = Some(PathBuf::from(value));
+                }
+                _ if arg.starts_with('-') => {
+                    return Err(format!("unknown option: '{arg}'"));
+                }
+                _ => {
+                    let range = parse_days(&arg)?;
+                    if range.is_empty() {
+                        return Err(format!("empty day range: '{arg}'"));
+                    }
+                    ranges.push(range);
+                }
+            }
+        }
+
+        // The days can only be checked once the year is known.
+        let available: Vec<u8> = year.days.iter().map(|d| d.day()).collect();
+        let mut days = vec![];
+        for day in ranges.into_iter().flatten() {
+            if !available.contains(&day) {
+                return Err(format!(
+                    "unknown day: {day} (available in {}: {}..={})",
+                    year.year,
+                    available[0],
+                    available[available.len() - 1]
+                ));
+            }
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+        if days.is_empty() {
+            days = available;
+        }
+        days.sort_unstable();
+
+        if !any_day_inputs.is_empty() {
+            if days.len() != 1 || any_day_inputs.len() != 1 {
+                return Err("--input without a day requires exactly one selected day".to_owned());
+            }
+            inputs.extend(any_day_inputs.into_iter().map(|path| (days[0], path)));
+        }
+        for (idx, (day, path)) in inputs.iter().enumerate() {
+            if !days.contains(day) {
+                return Err(format!(
+                    "--input given for day {day}, which is not selected"
+                ));
+            }
+            if inputs[..idx].iter().any(|(d, _)| d == day) {
+                return Err(format!("--input given more than once for day {day}"));
+            }
+            if path.as_os_str() == "-" && inputs[..idx].iter().any(|(_, p)| p == path) {
+                return Err("stdin can only be used as the input for one day".to_owned());
+            }
+        }
+
+        // someone else's input won't have the same answers as ours
+        let answers = match answers {
+            Some(path) if path.as_os_str() == "none" => None,
+            Some(path) => Some(path),
+            None if !inputs.is_empty() => None,
+            None => Some(match &input_dir {
+                Some(dir) => dir.join("answers.txt"),
+                None => answers_file(year.year),
+            }),
+        };
+        if record && answers.is_none() {
+            return Err("--record needs an answers file to record in".to_owned());
+        }
+
+        Ok(Some(Self {
+            year,
+            days,
+            part,
+            inputs,
+            answers,
+            input_dir,
+            format,
+            check: check || record,
+            record,
+            bench,
+            save_baseline,
+            compare,
+            baseline: baseline
+                .unwrap_or_else(|| PathBuf::from(format!("target/aoc-baseline-{}.txt", year.year))),
+            threshold: threshold / 100.0,
+        }))
+    }
+
+    fn read_input(&self, day: u8) -> Result<Option<Cow<'static, str>>, String> {
+        match self.inputs.iter().find(|(d, _)| *d == day) {
+            Some((_, path)) if path.as_os_str() == "-" => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("failed to read stdin: {e}"))?;
+                Ok(Some(Cow::Owned(input)))
+            }
+            Some((_, path)) => read_file(path).map(Some),
+            None => match &self.input_dir {
+                Some(dir) => read_file(&dir.join(format!("day{day}.txt"))).map(Some),
+                None => default_input(self.year.year, day),
+            },
+        }
+    }
+}
+
+pub fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut baseline = if args.save_baseline || args.compare {
+        match Baseline::load(&args.baseline) {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("error: failed to load {}: {e}", args.baseline.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Baseline::default()
+    };
+    if args.compare && !baseline.has_year(args.year.year) {
+        eprintln!(
+            "error: no baseline timings for {} in {}, run with --save-baseline first",
+            args.year.year,
+            args.baseline.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let mut answers = match &args.answers {
+        Some(path) if args.check => match Answers::load(path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("error: failed to load {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => Answers::default(),
+    };
+    let mut wrong = 0;
+    let mut recorded = 0;
+
+    let bench_config = bench::Config::default();
     let instant = Instant::now();
-    run_day_with_generator!(day_01, "1");
-    run_day_with_generator!(day_02, "2");
-    run_day!(day_03, "3");
-    run_day_with_generator!(day_04, "4");
-    run_day_with_generator!(day_05, "5");
-    run_day!(day_06, "6");
-    run_day_with_generator!(day_07, "7");
-    run_day_with_generator!(day_08, "8");
-    run_day_with_generator!(day_09, "9");
-
-    println!("done in {:?}", instant.elapsed());
+    let mut status = ExitCode::SUCCESS;
+    let mut regressions = 0;
+    let mut reports = vec![];
+    for day in args.year.days {
+        if !args.days.contains(&day.day()) {
+            continue;
+        }
+
+        let input = match args.read_input(day.day()) {
+            Ok(Some(input)) => input,
+            Ok(None) => {
+                eprintln!(
+                    "skipping day {}: {}/day{}.txt was missing when the runner was built\n",
+                    day.day(),
+                    args.year.input_dir().display(),
+                    day.day()
+                );
+                continue;
+            }
+            Err(e) => {
+                eprintln!("error: day {}: {e}\n", day.day());
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let report = if args.bench {
+            bench_day(*day, &input, args.part, &bench_config)
+        } else {
+            run_day(*day, &input, args.part)
+        };
+        let mut report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("{e}\n");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        if args.check {
+            for answer in &mut report.answers {
+                let verdict = answers.check(report.day, answer.part, &answer.answer);
+                match verdict {
+                    Verdict::Wrong { .. } => wrong += 1,
+                    Verdict::Unknown if args.record => {
+                        answers.insert(report.day, answer.part, &answer.answer);
+                        recorded += 1;
+                    }
+                    _ => {}
+                }
+                answer.verdict = Some(verdict);
+            }
+        }
+
+        let text = args.format == Format::Text;
+        if text {
+            print_report(&report, input.len(), args.bench);
+        }
+        if args.compare {
+            let (lines, regressed) =
+                compare_report(args.year.year, &report, &baseline, args.threshold);
+            regressions += regressed;
+            for line in lines {
+                // keep structured output on stdout parseable
+                if text {
+                    println!("{line}");
+                } else {
+                    eprintln!("{line}");
+                }
+            }
+        }
+        if text {
+            println!();
+        }
+
+        reports.push(report);
+    }
+
+    if args.format != Format::Text {
+        print_records(args.year.year, &reports, args.format);
+    }
+    if args.save_baseline {
+        for report in &reports {
+            for (phase, stats) in &report.timings {
+                baseline.insert(args.year.year, report.day, *phase, stats.median);
+            }
+        }
+        if let Err(e) = baseline.save(&args.baseline) {
+            eprintln!("error: failed to save {}: {e}", args.baseline.display());
+            status = ExitCode::FAILURE;
+        }
+    }
+    if let Some(path) = args.answers.as_ref().filter(|_| recorded > 0) {
+        match answers.save(path) {
+            Ok(()) => eprintln!("recorded {recorded} new answer(s) in {}", path.display()),
+            Err(e) => {
+                eprintln!("error: failed to save {}: {e}", path.display());
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(path) = args.answers.as_ref().filter(|_| wrong > 0) {
+        eprintln!("{wrong} answer(s) did not match {}", path.display());
+        status = ExitCode::FAILURE;
+    }
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} phase(s) regressed by more than {}%",
+            100.0 * args.threshold
+        );
+        status = ExitCode::FAILURE;
+    }
+
+    if args.format == Format::Text {
+        println!("done in {:?}", instant.elapsed());
+    }
+    status
 }
@@ -0,0 +1,114 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use advent_of_code_2025::{inputs::InputCache, latest_year, scaffold::*};
+
+const USAGE: &str = "\
+Usage: scaffold <DAY> [--year <YEAR>] [--title <TITLE>]
+
+Creates src/year_YYYY/day_NN/mod.rs from the template in src/day_xx, registers it in
+src/year_YYYY/mod.rs, and fetches the input. The year defaults to the latest one, and its module
+is created and registered in src/lib.rs if it's new. Existing modules are never overwritten.";
+
+/// Writes `contents` to `path`, unless it already exists.
+fn create_file(path: &Path, contents: &str) -> Result<(), String> {
+    if path.exists() {
+        println!("{} already exists, leaving it untouched", path.display());
+        return Ok(());
+    }
+
+    let dir = path.parent().unwrap();
+    fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+    fs::write(path, contents).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+fn update_file(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    let old =
+        fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let new = update(&old).map_err(|e| format!("failed to update {}: {e}", path.display()))?;
+    if new != old {
+        fs::write(path, new).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+        println!("updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn scaffold(year: u16, day: u8, title: &str) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let src = root.join("src");
+    let year_dir = src.join(year_module_name(year));
+
+    create_file(&year_dir.join("mod.rs"), &render_year_template(year))?;
+    update_file(&src.join("lib.rs"), |lib_rs| register_year(lib_rs, year))?;
+
+    let module_file = year_dir.join(module_name(day)).join("mod.rs");
+    create_file(&module_file, &render_template(year, day, title))?;
+    update_file(&year_dir.join("mod.rs"), |year_mod_rs| {
+        register_day(year_mod_rs, day)
+    })?;
+
+    let inputs = InputCache::from_env(root);
+    if inputs.is_cached(year, day) {
+        println!("{} already exists", inputs.path(year, day).display());
+    } else {
+        match inputs.get(year, day) {
+            Ok(_) => println!("fetched {}", inputs.path(year, day).display()),
+            Err(e) => eprintln!("warning: failed to fetch the input: {e}"),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut day = None;
+    let mut year = latest_year().year;
+    let mut title = String::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            "-y" | "--year" => match args.next().and_then(|value| value.parse().ok()) {
+                Some(value @ 2015..) => year = value,
+                _ => {
+                    eprintln!("error: missing or invalid value for --year\n\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-t" | "--title" => match args.next() {
+                Some(value) => title = value,
+                None => {
+                    eprintln!("error: missing value for --title\n\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            _ => match arg.parse::<u8>() {
+                Ok(d @ 1..=25) if day.is_none() => day = Some(d),
+                _ => {
+                    eprintln!("error: unexpected argument: '{arg}'\n\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        }
+    }
+
+    let Some(day) = day else {
+        eprintln!("error: missing day\n\n{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    match scaffold(year, day, &title) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
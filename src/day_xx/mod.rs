@@ -1,35 +1,52 @@
-use nom::{
-    bytes::complete::*,
-    character::complete::*,
-    multi::*,
-    sequence::tuple,
-    Parser,
+use std::fmt::Display;
+
+use nom::{Parser, character::complete::*, combinator::cut, multi::*};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
 };
 
 pub struct Input {
+    nums: Vec<u64>,
+}
 
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    let (input, nums) = separated_list1(line_ending, cut(u64)).parse(input)?;
+    Ok((input, Input { nums }))
 }
 
-fn parse_input(input: &str) -> nom::IResult<&str, Input> {
-    Ok((
-        input,
-        Input {
-        },
-    ))
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    finish(Dayxx::DAY, input, parse_input(input.trim_end()))
 }
 
-pub fn input_generator(input: &str) -> Input {
-    let (remaining, result) = parse_input(input).expect("failed to parse input");
-    assert!(remaining.trim().is_empty(), "failed to parse entire input");
-    result
+pub fn part_1(input: &Input) -> u64 {
+    input.nums.len() as u64
 }
 
-pub fn part_1(input: &Input) -> u32 {
-    0
+pub fn part_2(input: &Input) -> u64 {
+    input.nums.iter().sum()
 }
 
-pub fn part_2(input: &Input) -> u32 {
-    0
+pub struct Dayxx;
+
+impl Solution for Dayxx {
+    const DAY: u8 = xx;
+    const TITLE: &'static str = "";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -41,17 +58,22 @@ mod tests {
     fn test() {
         let input = input_generator(indoc! {
             "
+            1
+            2
+            3
             "
-        });
-        assert_eq!(part_1(&input), );
-        // assert_eq!(part_2(&input),);
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 3);
+        assert_eq!(part_2(&input), 6);
     }
 
-    #[cfg(input_exists)]
+    #[cfg(input_exists = "yyyy/dayxx")]
     #[test]
     fn test_my_input() {
-        let input = input_generator(include_str!("../../input/2025/dayxx.txt"));
-        // assert_eq!(part_1(&input), );
-        // assert_eq!(part_2(&input),);
+        crate::answers::assert_my_answers::<Dayxx>(
+            yyyy,
+            include_str!("../../../input/yyyy/dayxx.txt"),
+        );
     }
 }
@@ -0,0 +1,161 @@
+use std::{borrow::Cow, fmt};
+
+use nom::error::{ContextError, ErrorKind};
+
+/// A malformed puzzle input, located at the line and column where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The full text of the offending line.
+    pub snippet: String,
+    pub expected: Cow<'static, str>,
+}
+
+impl ParseError {
+    /// Creates an error located at `at`, which must be a subslice of `input`.
+    pub fn at(day: u8, input: &str, at: &str, expected: impl Into<Cow<'static, str>>) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "error location is outside the input");
+        Self::at_offset(day, input, offset, expected)
+    }
+
+    /// Creates an error located at the byte `offset` into `input`.
+    pub fn at_offset(
+        day: u8,
+        input: &str,
+        offset: usize,
+        expected: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        Self {
+            day,
+            line: 1 + input[..line_start].matches('\n').count(),
+            column: 1 + input[line_start..offset].chars().count(),
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: day {}: expected {}", self.day, self.expected)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The error type for the nom parsers, which keeps track of what the failing parser expected.
+#[derive(Debug)]
+pub struct NomError<'a> {
+    input: &'a str,
+    expected: Cow<'static, str>,
+}
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, NomError<'a>>;
+
+fn describe(kind: ErrorKind) -> Cow<'static, str> {
+    match kind {
+        ErrorKind::Digit => "a number".into(),
+        ErrorKind::CrLf => "a line ending".into(),
+        ErrorKind::Eof => "end of input".into(),
+        ErrorKind::OneOf => "one of the allowed characters".into(),
+        ErrorKind::Tag => "a tag".into(),
+        kind => kind.description().to_lowercase().into(),
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for NomError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            expected: describe(kind),
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self {
+            input,
+            expected: format!("{c:?}").into(),
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for NomError<'a> {
+    fn add_context(_: &'a str, ctx: &'static str, mut other: Self) -> Self {
+        other.expected = ctx.into();
+        other
+    }
+}
+
+/// Converts the result of parsing the whole of `input` into a located error, requiring that
+/// nothing but whitespace was left unparsed.
+pub fn finish<'a, T>(day: u8, input: &'a str, result: IResult<'a, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((remaining, value)) => {
+            let remaining = remaining.trim_start();
+            if remaining.is_empty() {
+                Ok(value)
+            } else {
+                Err(ParseError::at(day, input, remaining, "end of input"))
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(ParseError::at(day, input, e.input, e.expected))
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            Err(ParseError::at_offset(day, input, input.len(), "more input"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{Parser, character::complete::*, combinator::cut, multi::*};
+
+    fn parse(input: &str) -> IResult<'_, Vec<u32>> {
+        separated_list1(line_ending, cut(u32)).parse(input)
+    }
+
+    #[test]
+    fn test() {
+        let input = "1\n2\n3\n";
+        assert_eq!(finish(1, input, parse(input.trim_end())), Ok(vec![1, 2, 3]));
+
+        let input = "1\r\n2\r\nx3\r\n";
+        let err = finish(1, input, parse(input.trim_end())).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.snippet, "x3");
+        assert_eq!(
+            err.to_string(),
+            "error: day 1: expected a number\n --> line 3, column 1\n  |\n3 | x3\n  | ^"
+        );
+
+        let input = "1\n2 3";
+        let err = finish(1, input, parse(input)).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, &*err.expected),
+            (2, 3, "end of input")
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! A dense 2D grid, for the puzzles whose input is a character map.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The offsets to the orthogonal neighbours, in reading order.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The offsets to the orthogonal and diagonal neighbours, in reading order.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parses one row per line, requiring every row to have the same non-zero width and every
+    /// byte to pass `valid`, which is described by `expected` in errors. Line endings may be LF
+    /// or CRLF, and trailing newlines are ignored.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &'static str,
+        valid: impl Fn(u8) -> bool,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for row in input.trim_end().split('\n') {
+            let row = row.strip_suffix('\r').unwrap_or(row);
+            if let Some(idx) = row.bytes().position(|b| !valid(b)) {
+                return Err(ParseError::at(day, input, &row[idx..], expected));
+            }
+
+            let width = *width.get_or_insert(row.len());
+            if width == 0 {
+                let expected = format!("a row of {expected}");
+                return Err(ParseError::at(day, input, row, expected));
+            }
+            if row.len() != width {
+                let at = &row[row.len().min(width)..];
+                let expected = format!("a row of width {width}");
+                return Err(ParseError::at(day, input, at, expected));
+            }
+
+            cells.extend_from_slice(row.as_bytes());
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap(),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "cells don't fill the grid"
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_cells(width, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.contains(p)
+            .then(|| &mut self.cells[p.y * self.width + p.x])
+    }
+
+    /// Moves `p` by `(dx, dy)`, returning `None` if that leaves the grid.
+    pub fn offset(&self, p: Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let p = Point::new(p.x.checked_add_signed(dx)?, p.y.checked_add_signed(dy)?);
+        self.contains(p).then_some(p)
+    }
+
+    /// Moves `p` by `(dx, dy)`, wrapping around the edges of the grid, which must not be empty.
+    pub fn wrapping_offset(&self, p: Point, (dx, dy): (isize, isize)) -> Point {
+        let wrap = |n: usize, d: isize, len: usize| {
+            let d = d.rem_euclid(len as isize) as usize;
+            (n + d) % len
+        };
+        Point::new(wrap(p.x, dx, self.width), wrap(p.y, dy, self.height))
+    }
+
+    /// The orthogonal neighbours of `p` within the grid.
+    pub fn neighbours_4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |d| self.offset(p, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` within the grid.
+    pub fn neighbours_8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |d| self.offset(p, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl ExactSizeIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every point in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, in row-major order, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The first point, in row-major order, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        let idx = self.cells.iter().position(predicate)?;
+        Some(Point::new(idx % self.width, idx / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+/// Prints one line per row, with each cell as a character.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let row: String = row.iter().map(|&cell| cell.into()).collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let grid = Grid::parse(1, "#.#\r\n..#\r\n\r\n", "'#' or '.'", |b| {
+            b"#.".contains(&b)
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], b'#');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), b"..#");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"##");
+        assert_eq!(grid.columns().len(), 3);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"#.#", b"..#"]);
+        assert_eq!(grid.find(&b'.'), Some(Point::new(1, 0)));
+        assert_eq!(grid.position(|&b| b == b'@'), None);
+        assert_eq!(grid.iter().filter(|(_, b)| **b == b'#').count(), 3);
+        assert_eq!(grid.to_string(), "#.#\n..#\n");
+
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            grid.neighbours_4(corner).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(corner).count(), 3);
+        assert_eq!(grid.neighbours_8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.wrapping_offset(corner, (-1, -1)), Point::new(2, 1));
+        assert_eq!(grid.wrapping_offset(corner, (7, -4)), Point::new(1, 0));
+
+        let mut counts = grid.map(|&b| (b == b'#') as u32);
+        counts[corner] += 1;
+        assert_eq!(counts.row(0), [2, 0, 1]);
+        assert_eq!(Grid::filled(2, 2, 'x').to_string(), "xx\nxx\n");
+
+        let err = Grid::parse(1, "##\n#\n", "'#'", |b| b == b'#').unwrap_err();
+        assert_eq!(
+            (err.line, err.column, &*err.expected),
+            (2, 2, "a row of width 2")
+        );
+        let err = Grid::parse(1, "##\n#x\n", "'#'", |b| b == b'#').unwrap_err();
+        assert_eq!((err.line, err.column, &*err.expected), (2, 2, "'#'"));
+        let err = Grid::parse(1, "\n", "'#'", |b| b == b'#').unwrap_err();
+        assert_eq!(
+            (err.line, err.column, &*err.expected),
+            (1, 1, "a row of '#'")
+        );
+    }
+}
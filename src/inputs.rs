@@ -0,0 +1,341 @@
+//! Fetching puzzle inputs from the Advent of Code website and caching them on disk.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The file holding the session token, relative to the project root. The `AOC_SESSION`
+/// environment variable takes precedence over it.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = "github.com/zaneduffield/advent-of-code-2025";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Io(io::Error),
+    /// The server responded, but not with the input.
+    Status {
+        code: u16,
+        body: String,
+    },
+    Http(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {SESSION_FILE}"
+            ),
+            FetchError::Io(e) => write!(f, "{e}"),
+            FetchError::Status { code, body } => {
+                write!(f, "server responded with status {code}: {}", body.trim())
+            }
+            FetchError::Http(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used to download inputs.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, FetchError>;
+}
+
+/// Shells out to `curl`, which is needed for HTTPS.
+pub struct Curl;
+
+impl HttpClient for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, FetchError> {
+        // The headers are passed on stdin to keep the session token out of the process list.
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--location"])
+            .args(["--header", "@-", "--write-out", "\n%{http_code}", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stdin = child.stdin.take().unwrap();
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+        drop(stdin);
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(FetchError::Http(format!("curl failed: {}", stderr.trim())));
+        }
+
+        let stdout = String::from_utf8(output.stdout)
+            .map_err(|_| FetchError::Http("response is not valid UTF-8".to_owned()))?;
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| FetchError::Http("missing status code from curl".to_owned()))?;
+        let status = status
+            .parse()
+            .map_err(|_| FetchError::Http(format!("invalid status code from curl: {status}")))?;
+        Ok(Response {
+            status,
+            body: body.to_owned(),
+        })
+    }
+}
+
+/// A minimal HTTP/1.1 client over a plain TCP connection, for `http://` URLs only.
+pub struct PlainHttp;
+
+impl HttpClient for PlainHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, FetchError> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| FetchError::Http(format!("unsupported URL: {url}")))?;
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let path = if path.is_empty() { "/" } else { path };
+
+        let mut stream = if authority.contains(':') {
+            TcpStream::connect(authority)?
+        } else {
+            TcpStream::connect((authority, 80))?
+        };
+
+        let mut request = format!("GET {path} HTTP/1.1\r\nHost: {authority}\r\n");
+        for (name, value) in headers {
+            request += &format!("{name}: {value}\r\n");
+        }
+        request += "Connection: close\r\n\r\n";
+        stream.write_all(request.as_bytes())?;
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response)?;
+        let response = String::from_utf8(response)
+            .map_err(|_| FetchError::Http("response is not valid UTF-8".to_owned()))?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| FetchError::Http("malformed HTTP response".to_owned()))?;
+        let mut head = head.lines();
+        let status = head
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| FetchError::Http("malformed HTTP status line".to_owned()))?;
+        if head.any(|line| line.eq_ignore_ascii_case("transfer-encoding: chunked")) {
+            return Err(FetchError::Http(
+                "chunked responses are not supported".to_owned(),
+            ));
+        }
+
+        Ok(Response {
+            status,
+            body: body.to_owned(),
+        })
+    }
+}
+
+/// Uses [`PlainHttp`] for `http://` URLs and [`Curl`] for everything else.
+pub struct DefaultClient;
+
+impl HttpClient for DefaultClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, FetchError> {
+        if url.starts_with("http://") {
+            PlainHttp.get(url, headers)
+        } else {
+            Curl.get(url, headers)
+        }
+    }
+}
+
+/// Puzzle inputs cached under `<dir>/<year>/day<day>.txt`, fetched on first use.
+pub struct InputCache<C = DefaultClient> {
+    dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    client: C,
+}
+
+impl InputCache {
+    /// Caches inputs in the `input` directory under `root`, taking the base URL from
+    /// `AOC_BASE_URL` and the session token from `AOC_SESSION` or [`SESSION_FILE`].
+    pub fn from_env(root: &Path) -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(root.join(SESSION_FILE)).ok())
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+        Self::new(root.join("input"), base_url, session, DefaultClient)
+    }
+}
+
+impl<C: HttpClient> InputCache<C> {
+    pub fn new(
+        dir: impl Into<PathBuf>,
+        base_url: impl Into<String>,
+        session: Option<String>,
+        client: C,
+    ) -> Self {
+        Self {
+            dir: dir.into(),
+            base_url: base_url.into(),
+            session,
+            client,
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.path(year, day).is_file()
+    }
+
+    /// Returns the cached input, fetching and caching it if this is the first time it's needed.
+    pub fn get(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let input = self.fetch(year, day)?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        // Write to a temporary file first so that an interrupted write is never mistaken for a
+        // cached input.
+        let tmp = path.with_extension("txt.tmp");
+        fs::write(&tmp, &input)?;
+        fs::rename(&tmp, &path)?;
+        Ok(input)
+    }
+
+    /// Downloads the input, bypassing the cache.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+        let url = format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        );
+        let cookie = format!("session={session}");
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        match response.status {
+            200 => Ok(response.body),
+            code => Err(FetchError::Status {
+                code,
+                body: response.body,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+    };
+
+    /// Serves day 1 of 2025 to requests with the right session cookie, counting the requests.
+    fn stub_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let lines: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let (status, body) = if !lines.iter().any(|l| l == "Cookie: session=secret") {
+                    ("400 Bad Request", "Puzzle inputs differ by user.")
+                } else if lines[0] == "GET /2025/day/1/input HTTP/1.1" {
+                    ("200 OK", "L68\nR48\n")
+                } else {
+                    ("404 Not Found", "Not found")
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test() {
+        let (base_url, requests) = stub_server();
+        let dir = env::temp_dir().join(format!("aoc-inputs-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let cache = InputCache::new(&dir, &base_url, Some("secret".to_owned()), PlainHttp);
+        assert!(!cache.is_cached(2025, 1));
+        assert_eq!(cache.get(2025, 1).unwrap(), "L68\nR48\n");
+        assert!(cache.is_cached(2025, 1));
+        assert_eq!(
+            fs::read_to_string(dir.join("2025/day1.txt")).unwrap(),
+            "L68\nR48\n"
+        );
+        assert_eq!(cache.get(2025, 1).unwrap(), "L68\nR48\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            cache.get(2025, 2),
+            Err(FetchError::Status { code: 404, .. })
+        ));
+        assert!(!cache.is_cached(2025, 2));
+
+        let cache = InputCache::new(&dir, &base_url, Some("wrong".to_owned()), PlainHttp);
+        assert!(matches!(
+            cache.fetch(2025, 1),
+            Err(FetchError::Status { code: 400, .. })
+        ));
+
+        let cache = InputCache::new(&dir, &base_url, None, PlainHttp);
+        assert_eq!(cache.get(2025, 1).unwrap(), "L68\nR48\n");
+        assert!(matches!(
+            cache.get(2025, 3),
+            Err(FetchError::MissingSession)
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,253 @@
+//! A set of integers stored as disjoint inclusive ranges.
+
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive};
+
+/// The integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + fmt::Debug {
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+    /// The number of values in `start..=end`, which must not be empty, or `None` if there are
+    /// more than fit in a `u128`.
+    fn count(start: Self, end: Self) -> Option<u128>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            fn checked_succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> Option<u128> {
+                (end.abs_diff(start) as u128).checked_add(1)
+            }
+        }
+    )*};
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Inclusive ranges of integers, merged so that no two of them overlap or touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// The end of each range, keyed by its start.
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Adds every value in `range`, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&s, &e)) = self.ranges.range(..start).next_back()
+            && e.checked_succ().is_none_or(|after| after >= start)
+        {
+            self.ranges.remove(&s);
+            start = s;
+            end = end.max(e);
+        }
+
+        loop {
+            let next = match end.checked_succ() {
+                Some(after) => self.ranges.range(start..=after).next(),
+                None => self.ranges.range(start..).next(),
+            };
+            let Some((&s, &e)) = next else {
+                break;
+            };
+            self.ranges.remove(&s);
+            end = end.max(e);
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    /// Removes every value in `range`, splitting any range that it falls inside.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&s, &e)) = self.ranges.range(..start).next_back()
+            && e >= start
+        {
+            // `s < start`, so this can't underflow
+            self.ranges.insert(s, start.checked_pred().unwrap());
+            if e > end {
+                self.ranges.insert(end.checked_succ().unwrap(), e);
+                return;
+            }
+        }
+
+        let inside: Vec<_> = self
+            .ranges
+            .range(start..=end)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in inside {
+            self.ranges.remove(&s);
+            if e > end {
+                self.ranges.insert(end.checked_succ().unwrap(), e);
+            }
+        }
+    }
+
+    /// The merged range containing `value`, in O(log n).
+    pub fn range_containing(&self, value: T) -> Option<RangeInclusive<T>> {
+        let (&start, &end) = self.ranges.range(..=value).next_back()?;
+        (end >= value).then_some(start..=end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of disjoint ranges.
+    pub fn num_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The number of values in the set, or `None` if there are more than fit in a `u128`, which
+    /// can only happen for sets of `u128`s or `i128`s.
+    pub fn len(&self) -> Option<u128> {
+        (self.ranges.iter()).try_fold(0u128, |len, (&s, &e)| len.checked_add(T::count(s, e)?))
+    }
+
+    /// The merged ranges, in increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&s, &e)| s..=e)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            let start = *ra.start().max(rb.start());
+            let end = *ra.end().min(rb.end());
+            if start <= end {
+                intersection.ranges.insert(start, end);
+            }
+            // whichever range ends first can't overlap anything else in the other set
+            if ra.end() < rb.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        intersection
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=5, 10..=20]);
+        assert_eq!(set.len(), Some(14));
+        assert!(set.contains(3) && set.contains(17));
+        assert!(!set.contains(6) && !set.contains(21) && !set.contains(0));
+        assert_eq!(set.range_containing(11), Some(10..=20));
+
+        // touching ranges are merged too
+        let mut set = set;
+        set.insert(RangeInclusive::new(9, 8));
+        assert_eq!(set.num_ranges(), 2);
+        set.insert(6..=9);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=20]);
+        set.remove(8..=10);
+        set.remove(20..=30);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=7, 11..=19]);
+        set.remove(0..=3);
+        assert_eq!(set.num_ranges(), 2);
+        assert_eq!(set.iter().next(), Some(4..=7));
+
+        let a: IntervalSet<i32> = [-5..=5, 10..=20].into_iter().collect();
+        let b: IntervalSet<i32> = [0..=12, 18..=25].into_iter().collect();
+        let ranges = |set: IntervalSet<i32>| set.iter().collect::<Vec<_>>();
+        assert_eq!(ranges(a.union(&b)), [-5..=25]);
+        assert_eq!(ranges(a.intersection(&b)), [0..=5, 10..=12, 18..=20]);
+        assert_eq!(ranges(a.difference(&b)), [-5..=-1, 13..=17]);
+        assert_eq!(ranges(b.difference(&a)), [6..=9, 21..=25]);
+
+        let mut full: IntervalSet<u8> = [0..=100, 101..=255].into_iter().collect();
+        assert_eq!(full.len(), Some(256));
+        full.remove(255..=255);
+        full.remove(0..=0);
+        assert_eq!(full.iter().collect::<Vec<_>>(), [1..=254]);
+        assert_eq!(IntervalSet::<u64>::new().len(), Some(0));
+
+        // too many values to count
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(full.len(), Some(1 << 64));
+        let mut full: IntervalSet<i128> = [i128::MIN..=i128::MAX].into_iter().collect();
+        assert_eq!(full.len(), None);
+        full.remove(0..=0);
+        assert_eq!(full.len(), Some(u128::MAX));
+        full.remove(1..=1);
+        full.insert(-1..=-1);
+        assert_eq!(full.len(), Some(u128::MAX - 1));
+        let halves: IntervalSet<u128> = [0..=u128::MAX / 2, u128::MAX / 2 + 2..=u128::MAX]
+            .into_iter()
+            .collect();
+        assert_eq!(halves.len(), Some(u128::MAX));
+    }
+}
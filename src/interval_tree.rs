@@ -0,0 +1,148 @@
+//! A static interval tree, for finding the inclusive ranges that contain a value.
+
+use std::ops::RangeInclusive;
+
+/// Possibly overlapping inclusive ranges, kept as they were given rather than merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalTree<T> {
+    /// The ranges sorted by start, arranged as a balanced tree rooted at the middle of each
+    /// subslice.
+    ranges: Vec<RangeInclusive<T>>,
+    /// The largest end in the subtree rooted at each range.
+    max_end: Vec<T>,
+}
+
+impl<T: Copy + Ord> IntervalTree<T> {
+    /// Builds the tree in O(n log n), ignoring any empty ranges.
+    pub fn new(ranges: impl IntoIterator<Item = RangeInclusive<T>>) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_unstable_by_key(|r| (*r.start(), *r.end()));
+        let mut max_end: Vec<_> = ranges.iter().map(|r| *r.end()).collect();
+        Self::build(&ranges, &mut max_end);
+        Self { ranges, max_end }
+    }
+
+    fn build(ranges: &[RangeInclusive<T>], max_end: &mut [T]) -> Option<T> {
+        if ranges.is_empty() {
+            return None;
+        }
+        let mid = ranges.len() / 2;
+        let left = Self::build(&ranges[..mid], &mut max_end[..mid]);
+        let right = Self::build(&ranges[mid + 1..], &mut max_end[mid + 1..]);
+        max_end[mid] = [left, right]
+            .into_iter()
+            .flatten()
+            .fold(max_end[mid], T::max);
+        Some(max_end[mid])
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges in order of their starts, then their ends.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    /// Whether any range contains `value`, in O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        let (mut ranges, mut max_end) = (&self.ranges[..], &self.max_end[..]);
+        while !ranges.is_empty() {
+            let mid = ranges.len() / 2;
+            if max_end[mid] < value {
+                return false;
+            }
+            // a range on the left that ends late enough also starts early enough, unless the
+            // middle one starts too late, in which case everything on the right does too
+            if mid > 0 && max_end[mid / 2] >= value {
+                (ranges, max_end) = (&ranges[..mid], &max_end[..mid]);
+                continue;
+            }
+            if ranges[mid].contains(&value) {
+                return true;
+            }
+            (ranges, max_end) = (&ranges[mid + 1..], &max_end[mid + 1..]);
+        }
+        false
+    }
+
+    /// Every range that contains `value`, in order, in O((k + 1) log n) for k of them.
+    pub fn containing(&self, value: T) -> Vec<&RangeInclusive<T>> {
+        let mut found = vec![];
+        Self::search(&self.ranges, &self.max_end, value, &mut found);
+        found
+    }
+
+    fn search<'a>(
+        ranges: &'a [RangeInclusive<T>],
+        max_end: &[T],
+        value: T,
+        found: &mut Vec<&'a RangeInclusive<T>>,
+    ) {
+        if ranges.is_empty() {
+            return;
+        }
+        let mid = ranges.len() / 2;
+        if max_end[mid] < value {
+            return;
+        }
+
+        Self::search(&ranges[..mid], &max_end[..mid], value, found);
+        // everything after a range that starts too late does too
+        if *ranges[mid].start() > value {
+            return;
+        }
+        if *ranges[mid].end() >= value {
+            found.push(&ranges[mid]);
+        }
+        Self::search(&ranges[mid + 1..], &max_end[mid + 1..], value, found);
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<RangeInclusive<T>> for IntervalTree<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        Self::new(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    #[test]
+    fn test() {
+        let tree: IntervalTree<u64> = [10..=14, 3..=5, 16..=20, 12..=18, 1..=30]
+            .into_iter()
+            .collect();
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.containing(13), [&(1..=30), &(10..=14), &(12..=18)]);
+        assert_eq!(tree.containing(31), [] as [&RangeInclusive<u64>; 0]);
+        assert!(tree.contains(30) && !tree.contains(0));
+        assert!(IntervalTree::new([RangeInclusive::new(5, 4)]).is_empty());
+
+        // against checking every range
+        let mut rng = Lcg::new(12345);
+        let mut next = || rng.below(200);
+        for len in [0, 1, 2, 7, 50] {
+            let ranges: Vec<_> = (0..len)
+                .map(|_| {
+                    let start = next();
+                    start..=start + next() % 20
+                })
+                .collect();
+            let tree = IntervalTree::new(ranges.clone());
+            for value in 0..230 {
+                let mut expected: Vec<_> = ranges.iter().filter(|r| r.contains(&value)).collect();
+                expected.sort_unstable_by_key(|r| (*r.start(), *r.end()));
+                assert_eq!(tree.containing(value), expected);
+                assert_eq!(tree.contains(value), !expected.is_empty());
+            }
+        }
+    }
+}
@@ -1,9 +1,74 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod inputs;
+pub mod interval_set;
+pub mod interval_tree;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+mod test_util;
+pub mod union_find;
+pub mod year_2025;
+
+use std::path::PathBuf;
+
+pub use error::ParseError;
+pub use solution::{DynSolution, Parsed, Solution};
+
+/// The days implemented for one event.
+pub struct Year {
+    pub year: u16,
+    /// Every implemented day, in order.
+    pub days: &'static [&'static dyn DynSolution],
+}
+
+impl Year {
+    /// Looks up a day in [`Year::days`].
+    pub fn find_day(&self, day: u8) -> Option<&'static dyn DynSolution> {
+        self.days.iter().copied().find(|d| d.day() == day)
+    }
+
+    /// Where the inputs and answers for this year are kept.
+    pub fn input_dir(&self) -> PathBuf {
+        input_dir(self.year)
+    }
+}
+
+/// Every event with solutions, in order.
+pub const YEARS: &[Year] = &[year_2025::YEAR];
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent event, which the binaries default to.
+pub fn latest_year() -> &'static Year {
+    YEARS.last().expect("no years registered")
+}
+
+/// Where the inputs and answers for `year` are kept, relative to the project root.
+pub fn input_dir(year: u16) -> PathBuf {
+    PathBuf::from(format!("input/{year}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+        for year in YEARS {
+            assert!(year.days.windows(2).all(|w| w[0].day() < w[1].day()));
+        }
+
+        let year = find_year(2025).unwrap();
+        assert_eq!(year.find_day(4).unwrap().title(), "Printing Department");
+        assert!(year.find_day(0).is_none());
+        assert!(find_year(2015).is_none());
+        assert_eq!(year.input_dir(), PathBuf::from("input/2025"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Generating and registering new day modules, used by the `scaffold` binary.
+
+/// The template for a new day, with `yyyy` standing in for the year and `xx` for the day number.
+pub const TEMPLATE: &str = include_str!("day_xx/mod.rs");
+
+/// The template for the module of a new year, which its days are then registered in.
+const YEAR_TEMPLATE: &str = "\
+use crate::{DynSolution, Year};
+
+/// Every implemented day, in order.
+pub const DAYS: &[&dyn DynSolution] = &[];
+
+pub const YEAR: Year = Year {
+    year: yyyy,
+    days: DAYS,
+};
+";
+
+pub fn year_module_name(year: u16) -> String {
+    format!("year_{year}")
+}
+
+pub fn module_name(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+pub fn struct_name(day: u8) -> String {
+    format!("Day{day:02}")
+}
+
+/// Fills in the year, day number and title in [`TEMPLATE`].
+pub fn render_template(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("Dayxx", &struct_name(day))
+        .replace("yyyy", &year.to_string())
+        .replace("xx", &day.to_string())
+        .replace(
+            "const TITLE: &'static str = \"\";",
+            &format!("const TITLE: &'static str = {title:?};"),
+        )
+}
+
+/// The module for a new year, without any days.
+pub fn render_year_template(year: u16) -> String {
+    YEAR_TEMPLATE.replace("yyyy", &year.to_string())
+}
+
+/// Adds `mod_decl` among the sorted module declarations in `source`, and `entry` to the sorted
+/// list in the `registry` constant, unless they are already there.
+///
+/// The list is laid out the way rustfmt would, on one line if it fits and one entry per line
+/// otherwise.
+fn register(source: &str, mod_decl: &str, registry: &str, entry: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = source.lines().map(str::to_owned).collect();
+
+    if !lines.iter().any(|line| line == mod_decl) {
+        let mut mods = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("pub mod "));
+        let idx = match mods.clone().find(|(_, line)| line.as_str() > mod_decl) {
+            Some((idx, _)) => idx,
+            None => mods.next_back().map_or(0, |(idx, _)| idx + 1),
+        };
+        lines.insert(idx, mod_decl.to_owned());
+        if lines
+            .get(idx + 1)
+            .is_some_and(|line| !line.is_empty() && !line.starts_with("pub mod "))
+        {
+            lines.insert(idx + 1, String::new());
+        }
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("pub const {registry}:")))
+        .ok_or_else(|| format!("no {registry} registry found"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.ends_with("];"))
+            .ok_or_else(|| format!("unterminated {registry} registry"))?;
+    let statement = lines[start..=end].join("\n");
+    let (head, list) = statement
+        .split_once("= &[")
+        .ok_or_else(|| format!("{registry} is not a slice literal"))?;
+    let mut entries: Vec<&str> = list
+        .trim_end_matches("];")
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect();
+    if !entries.contains(&entry) {
+        entries.push(entry);
+        entries.sort_unstable();
+    }
+
+    let one_line = format!("{head}= &[{}];", entries.join(", "));
+    let replacement = if one_line.len() <= 100 {
+        vec![one_line]
+    } else {
+        let mut replacement = vec![format!("{head}= &[")];
+        replacement.extend(entries.iter().map(|entry| format!("    {entry},")));
+        replacement.push("];".to_owned());
+        replacement
+    };
+    lines.splice(start..=end, replacement);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the module declaration and registry entry for `day` to the source of its year's
+/// `mod.rs`, leaving it unchanged if the day is already registered.
+pub fn register_day(year_mod_rs: &str, day: u8) -> Result<String, String> {
+    let module = module_name(day);
+    register(
+        year_mod_rs,
+        &format!("pub mod {module};"),
+        "DAYS",
+        &format!("&{module}::{}", struct_name(day)),
+    )
+}
+
+/// Adds the module declaration and registry entry for `year` to the source of `lib.rs`, leaving
+/// it unchanged if the year is already registered.
+pub fn register_year(lib_rs: &str, year: u16) -> Result<String, String> {
+    let module = year_module_name(year);
+    register(
+        lib_rs,
+        &format!("pub mod {module};"),
+        "YEARS",
+        &format!("{module}::YEAR"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let module = render_template(2025, 10, "Factory");
+        assert!(module.contains("pub struct Day10;"));
+        assert!(module.contains("const DAY: u8 = 10;"));
+        assert!(module.contains("const TITLE: &'static str = \"Factory\";"));
+        assert!(module.contains("include_str!(\"../../../input/2025/day10.txt\")"));
+        assert!(!module.contains("xx"));
+        assert!(!module.contains("yyyy"));
+
+        let year_mod_rs = indoc! {"
+            pub mod day_01;
+            pub mod day_02;
+            pub mod day_03;
+            pub mod day_04;
+            pub mod day_05;
+            pub mod day_09;
+
+            use crate::{DynSolution, Year};
+
+            pub const DAYS: &[&dyn DynSolution] = &[
+                &day_01::Day01,
+                &day_02::Day02,
+                &day_03::Day03,
+                &day_04::Day04,
+                &day_05::Day05,
+                &day_09::Day09,
+            ];
+        "};
+        let registered = register_day(year_mod_rs, 10).unwrap();
+        assert!(registered.contains("pub mod day_09;\npub mod day_10;\n\nuse crate::"));
+        assert!(registered.contains("    &day_09::Day09,\n    &day_10::Day10,\n];"));
+        assert_eq!(register_day(&registered, 10).unwrap(), registered);
+        assert!(register_day(&registered, 7).unwrap().contains(indoc! {"
+            pub mod day_05;
+            pub mod day_07;
+            pub mod day_09;
+        "}));
+
+        let new_year = register_day(&render_year_template(2026), 1).unwrap();
+        assert!(new_year.starts_with("pub mod day_01;\n\nuse crate::"));
+        assert!(new_year.contains("pub const DAYS: &[&dyn DynSolution] = &[&day_01::Day01];"));
+        assert!(new_year.contains("year: 2026,"));
+
+        let lib_rs = indoc! {"
+            pub mod solution;
+            pub mod year_2025;
+
+            pub const YEARS: &[Year] = &[year_2025::YEAR];
+        "};
+        let registered = register_year(lib_rs, 2026).unwrap();
+        assert_eq!(
+            registered,
+            indoc! {"
+                pub mod solution;
+                pub mod year_2025;
+                pub mod year_2026;
+
+                pub const YEARS: &[Year] = &[year_2025::YEAR, year_2026::YEAR];
+            "}
+        );
+        assert_eq!(register_year(&registered, 2026).unwrap(), registered);
+    }
+}
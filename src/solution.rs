@@ -0,0 +1,57 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use crate::error::ParseError;
+
+/// A single day's puzzle, split into parsing and the two parts so that each phase can be timed
+/// separately.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_1(input: &Self::Input<'_>) -> impl Display;
+    fn part_2(input: &Self::Input<'_>) -> impl Display;
+}
+
+/// Object-safe version of [`Solution`], implemented for every solution so that days can be
+/// listed in a registry and driven generically at runtime.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+}
+
+/// A parsed input, ready to have either part run on it.
+pub trait Parsed {
+    fn part_1(&self) -> String;
+    fn part_2(&self) -> String;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>, PhantomData<S>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part_1(&self) -> String {
+        S::part_1(&self.0).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        S::part_2(&self.0).to_string()
+    }
+}
+
+impl<S: Solution + Sync + 'static> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let parsed = S::parse(input)?;
+        Ok(Box::new(ParsedInput::<S>(parsed, PhantomData)))
+    }
+}
@@ -0,0 +1,23 @@
+//! Helpers shared between tests.
+
+/// A linear congruential generator, for generating test inputs that are random enough but the
+/// same on every run.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        // the low bits of an LCG with a power of two modulus repeat with short periods
+        (self.state >> 33) % n
+    }
+}
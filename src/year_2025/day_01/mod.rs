@@ -1,4 +1,11 @@
-use nom::{character::complete::*, multi::*, Parser};
+use std::fmt::Display;
+
+use nom::{Parser, character::complete::*, combinator::cut, error::context, multi::*};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
+};
 
 pub struct Input {
     instructions: Vec<(Dir, u16)>,
@@ -9,10 +16,10 @@ enum Dir {
     Right,
 }
 
-fn parse_input(input: &str) -> nom::IResult<&str, Input> {
-    let (input, instructions) = separated_list0(
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    let (input, instructions) = separated_list1(
         line_ending,
-        (one_of("LR"), u16)
+        cut((context("'L' or 'R'", one_of("LR")), u16))
             .map(|(dir, len)| (if dir == 'L' { Dir::Left } else { Dir::Right }, len)),
     )
     .parse(input)?;
@@ -20,10 +27,8 @@ fn parse_input(input: &str) -> nom::IResult<&str, Input> {
     Ok((input, Input { instructions }))
 }
 
-pub fn input_generator(input: &str) -> Input {
-    let (remaining, result) = parse_input(input).expect("failed to parse input");
-    assert!(remaining.trim().is_empty(), "failed to parse entire input");
-    result
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    finish(Day01::DAY, input, parse_input(input.trim_end()))
 }
 
 const INIT: i16 = 50;
@@ -71,6 +76,27 @@ pub fn part_2(input: &Input) -> u32 {
         .1
 }
 
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,7 +117,8 @@ mod tests {
             R14
             L82
             "
-        });
+        })
+        .unwrap();
         assert_eq!(part_1(&input), 3);
         assert_eq!(part_2(&input), 6);
 
@@ -99,15 +126,20 @@ mod tests {
             "
             L150
             "
-        });
+        })
+        .unwrap();
         assert_eq!(part_2(&input), 2);
+
+        let err = input_generator("L68\nX30\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
     }
 
-    #[cfg(input_exists)]
+    #[cfg(input_exists = "2025/day1")]
     #[test]
     fn test_my_input() {
-        let input = input_generator(include_str!("../../input/2025/day1.txt"));
-        assert_eq!(part_1(&input), 1018);
-        assert_eq!(part_2(&input), 5815);
+        crate::answers::assert_my_answers::<Day01>(
+            2025,
+            include_str!("../../../input/2025/day1.txt"),
+        );
     }
 }
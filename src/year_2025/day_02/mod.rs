@@ -0,0 +1,370 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, RangeInclusive, Rem, Sub},
+};
+
+use nom::{Parser, character::complete::*, combinator::cut, multi::*, sequence::separated_pair};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
+    interval_set::{Integer, IntervalSet},
+};
+
+pub struct Input {
+    /// Widened from the `u64`s in the input so that their sum can't overflow.
+    ///
+    /// Overlapping ranges are merged, so an ID in more than one of them only counts once.
+    ids: IntervalSet<u128>,
+}
+
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    let (input, ranges) = separated_list1(
+        char(','),
+        cut(separated_pair(u64, char('-'), u64))
+            .map(|(start, end)| u128::from(start)..=u128::from(end)),
+    )
+    .parse(input)?;
+    Ok((
+        input,
+        Input {
+            ids: ranges.into_iter().collect(),
+        },
+    ))
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    finish(Day02::DAY, input, parse_input(input.trim_end()))
+}
+
+/// The unsigned integer types that IDs can be stored in.
+pub trait Id:
+    Integer
+    + Display
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_id {
+    ($($t:ty),*) => {$(
+        impl Id for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_id!(u8, u16, u32, u64, u128, usize);
+
+/// The sum of some IDs didn't fit in their integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the sum of the invalid IDs overflowed")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// How many times an invalid ID repeats its block of digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeats {
+    Twice,
+    AtLeastTwice,
+}
+
+fn radix_as<T: Id>(radix: u32) -> T {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in 2..=36, not {radix}"
+    );
+    T::from(radix as u8)
+}
+
+/// The digits of `id`, least significant first.
+fn digits<T: Id>(mut id: T, radix: T) -> Vec<T> {
+    let mut digits = vec![];
+    while id != T::from(0) {
+        digits.push(id % radix);
+        id = id / radix;
+    }
+    digits
+}
+
+/// Whether `id`, written in `radix`, is a block of digits repeated `repeats` times.
+pub fn is_invalid_id<T: Id>(id: T, radix: u32, repeats: Repeats) -> bool {
+    let digits = digits(id, radix_as(radix));
+    let num_digits = digits.len();
+    let repeats_every = |width: usize| {
+        num_digits.is_multiple_of(width)
+            && digits.chunks(width).all(|block| block == &digits[..width])
+    };
+
+    match repeats {
+        Repeats::Twice => {
+            num_digits.is_multiple_of(2) && num_digits > 0 && repeats_every(num_digits / 2)
+        }
+        Repeats::AtLeastTwice => (1..=num_digits / 2).any(repeats_every),
+    }
+}
+
+fn checked_pow<T: Id>(base: T, exp: u32) -> Option<T> {
+    (0..exp).try_fold(T::from(1), |acc, _| acc.checked_mul(base))
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The distinct prime factors of `n`.
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while n > 1 {
+        if n.is_multiple_of(p) {
+            factors.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    factors
+}
+
+/// The sum of the `digits`-digit IDs in `range` that are a block of `period` digits repeated.
+///
+/// Each of them is its block times the repunit `1 + radix^period + radix^(2 period) + ...`, so
+/// the blocks in range form an arithmetic series. The caller must make sure that
+/// `radix^(digits - 1)` fits in `T`.
+fn sum_periodic<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: T,
+    digits: u32,
+    period: u32,
+) -> Result<T, Overflow> {
+    let (zero, one) = (T::from(0), T::from(1));
+    let step = checked_pow(radix, period).unwrap();
+    let Some(multiplier) =
+        (1..digits / period).try_fold(one, |acc, _| acc.checked_mul(step)?.checked_add(one))
+    else {
+        // every ID with this period is bigger than the repunit
+        return Ok(zero);
+    };
+
+    // blocks can't start with a zero
+    let smallest_block = checked_pow(radix, period - 1).unwrap();
+    let (start, end) = (*range.start(), *range.end());
+    let first = (start / multiplier
+        + if start % multiplier == zero {
+            zero
+        } else {
+            one
+        })
+    .max(smallest_block);
+    let last = (end / multiplier).min(step - one);
+    if first > last {
+        return Ok(zero);
+    }
+
+    // halve whichever factor is even, so that nothing bigger than the sum is ever computed
+    let count = last - first + one;
+    let ends = first.checked_add(last).ok_or(Overflow)?;
+    let (a, b) = if count % T::from(2) == zero {
+        (count / T::from(2), ends)
+    } else {
+        (count, ends / T::from(2))
+    };
+    multiplier
+        .checked_mul(a)
+        .and_then(|sum| sum.checked_mul(b))
+        .ok_or(Overflow)
+}
+
+/// The sum of the `digits`-digit IDs in `range` that repeat with any of `periods`.
+///
+/// An ID repeats with two periods exactly when it repeats with their gcd, so this adds the IDs of
+/// each period that don't repeat with any earlier one. Every partial sum is at most the total, so
+/// this only overflows if the total does.
+fn sum_periodic_any<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: T,
+    digits: u32,
+    periods: &[u32],
+) -> Result<T, Overflow> {
+    let Some((&period, earlier)) = periods.split_last() else {
+        return Ok(T::from(0));
+    };
+    let shared: Vec<_> = earlier.iter().map(|&p| gcd(p, period)).collect();
+    let new = sum_periodic(range, radix, digits, period)?
+        - sum_periodic_any(range, radix, digits, &shared)?;
+    sum_periodic_any(range, radix, digits, earlier)?
+        .checked_add(new)
+        .ok_or(Overflow)
+}
+
+/// The sum of the IDs in `ids` that, written in `radix`, are a block of digits repeated `repeats`
+/// times.
+///
+/// The IDs are generated directly rather than tested one by one, so this takes time proportional
+/// to the number of ranges and digits rather than to the size of the ranges.
+pub fn sum_invalid_ids<T: Id>(
+    ids: &IntervalSet<T>,
+    radix: u32,
+    repeats: Repeats,
+) -> Result<T, Overflow> {
+    let radix = radix_as(radix);
+    let mut sum = T::from(0);
+    for range in ids.iter() {
+        let mut digits = 2;
+        // while the smallest number with this many digits fits
+        while checked_pow(radix, digits - 1).is_some() {
+            // an ID repeating some block repeats one whose count of repetitions is prime
+            let periods: Vec<_> = match repeats {
+                Repeats::Twice if digits.is_multiple_of(2) => vec![digits / 2],
+                Repeats::Twice => vec![],
+                Repeats::AtLeastTwice => prime_factors(digits).iter().map(|p| digits / p).collect(),
+            };
+            let ids = sum_periodic_any(&range, radix, digits, &periods)?;
+            sum = sum.checked_add(ids).ok_or(Overflow)?;
+            digits += 1;
+        }
+    }
+    Ok(sum)
+}
+
+pub fn part_1(input: &Input) -> u128 {
+    sum_invalid_ids(&input.ids, 10, Repeats::Twice).expect("a sum of u64s fits in a u128")
+}
+
+pub fn part_2(input: &Input) -> u128 {
+    sum_invalid_ids(&input.ids, 10, Repeats::AtLeastTwice).expect("a sum of u64s fits in a u128")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let is_invalid_id1 = |id: u128| is_invalid_id(id, 10, Repeats::Twice);
+        let is_invalid_id2 = |id: u128| is_invalid_id(id, 10, Repeats::AtLeastTwice);
+        assert!(is_invalid_id1(1188511885));
+        assert!(!is_invalid_id1(1188511886));
+        assert!(!is_invalid_id1(1188521885));
+        assert!(is_invalid_id2(111111) && is_invalid_id2(123123123));
+        assert!(!is_invalid_id2(1231231234));
+        assert!(is_invalid_id(0b1010u8, 2, Repeats::Twice));
+        assert!(is_invalid_id(u128::MAX, 2, Repeats::AtLeastTwice));
+        assert!(is_invalid_id(35 * 37u16, 36, Repeats::Twice));
+        assert!(!is_invalid_id(0u32, 10, Repeats::AtLeastTwice));
+
+        let input = input_generator(indoc! {
+            "
+            11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+            824824821-824824827,2121212118-2121212124
+            "
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 1227775554);
+        assert_eq!(part_2(&input), 4174379265);
+
+        let input = input_generator("11-22,11-22,20-33").unwrap();
+        assert_eq!(part_1(&input), 11 + 22 + 33);
+
+        for (start, end) in [
+            (1, 20_000),
+            (998, 1012),
+            (123_123, 124_000),
+            (9_999_990, 10_000_010),
+            (18446744071844670000, 18446744071844680000),
+        ] {
+            let input = Input {
+                ids: IntervalSet::from_iter([start..=end]),
+            };
+            let sum =
+                |is_invalid: fn(u128) -> bool| (start..=end).filter(|&id| is_invalid(id)).sum();
+            assert_eq!(part_1(&input), sum(is_invalid_id1));
+            assert_eq!(part_2(&input), sum(is_invalid_id2));
+        }
+
+        fn check<T: Id + Into<u128> + TryFrom<u128>>(start: T, end: T) {
+            let ids = IntervalSet::from_iter([start..=end]);
+            for radix in [2, 10, 36] {
+                for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                    let expected: u128 = (start.into()..=end.into())
+                        .filter(|&id| is_invalid_id(id, radix, repeats))
+                        .sum();
+                    assert_eq!(
+                        sum_invalid_ids(&ids, radix, repeats),
+                        T::try_from(expected).map_err(|_| Overflow),
+                    );
+                }
+            }
+        }
+        check(0u8, u8::MAX);
+        check(100u8, 200);
+        check(65_000u16, u16::MAX);
+        check(1000u16, 2000);
+        check(u32::MAX - 2000, u32::MAX);
+        check(u64::MAX - 2000, u64::MAX);
+        check(u128::MAX - 2000, u128::MAX);
+        assert_eq!(
+            sum_invalid_ids(&IntervalSet::from_iter([0..=u128::MAX]), 2, Repeats::Twice),
+            Err(Overflow)
+        );
+
+        // far too many IDs to test one by one
+        let input = input_generator("1-18446744073709551615").unwrap();
+        assert!(part_2(&input) > part_1(&input));
+
+        let err = input_generator("11-22,95+115").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 9));
+    }
+
+    #[cfg(input_exists = "2025/day2")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day02>(
+            2025,
+            include_str!("../../../input/2025/day2.txt"),
+        );
+    }
+}
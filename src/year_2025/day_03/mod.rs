@@ -0,0 +1,334 @@
+use std::fmt::{self, Display};
+
+use crate::{Solution, error::ParseError};
+
+/// The batteries chosen from a bank to make the largest joltage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection<'a> {
+    bank: &'a str,
+    /// The positions of the chosen batteries in the bank, in increasing order.
+    positions: Vec<usize>,
+}
+
+impl<'a> Selection<'a> {
+    pub fn bank(&self) -> &'a str {
+        self.bank
+    }
+
+    pub fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    /// The joltage of each chosen battery, in order.
+    pub fn digits(&self) -> impl Iterator<Item = u8> + '_ {
+        let bank = self.bank.as_bytes();
+        self.positions.iter().map(|&idx| bank[idx] - b'0')
+    }
+
+    /// The joltage, or `None` if it doesn't fit in a `u128`. Its digits are always available from
+    /// the `Display` impl.
+    pub fn joltage(&self) -> Option<u128> {
+        self.digits().try_fold(0u128, |sum, digit| {
+            sum.checked_mul(10)?.checked_add(digit.into())
+        })
+    }
+}
+
+impl Display for Selection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bank = self.bank.as_bytes();
+        let digits: String = self
+            .positions
+            .iter()
+            .map(|&idx| bank[idx] as char)
+            .collect();
+        f.pad(&digits)
+    }
+}
+
+/// How [`Selection::render`] marks the chosen batteries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// Bold green, with ANSI escape codes.
+    Ansi,
+    /// A second line with a caret under each chosen battery.
+    Carets,
+}
+
+impl Selection<'_> {
+    /// The whole bank with the chosen batteries highlighted.
+    pub fn render(&self, highlight: Highlight) -> String {
+        let mut chosen = self.positions.iter().peekable();
+        let mut rendered = String::with_capacity(2 * self.bank.len());
+        let mut carets = String::new();
+        for (idx, c) in self.bank.char_indices() {
+            let is_chosen = chosen.next_if_eq(&&idx).is_some();
+            match highlight {
+                Highlight::Ansi if is_chosen => {
+                    rendered.push_str("\x1b[1;32m");
+                    rendered.push(c);
+                    rendered.push_str("\x1b[0m");
+                }
+                Highlight::Ansi => rendered.push(c),
+                Highlight::Carets => {
+                    rendered.push(c);
+                    carets.push(if is_chosen { '^' } else { ' ' });
+                }
+            }
+        }
+
+        if highlight == Highlight::Carets {
+            rendered.push('\n');
+            rendered.push_str(carets.trim_end());
+        }
+        rendered
+    }
+}
+
+/// Chooses `count` batteries from `bank` to make the largest joltage, in O(n) for a bank of n
+/// batteries.
+///
+/// The chosen batteries are kept on a stack, and a battery replaces any smaller ones before it
+/// while there are enough batteries left after it to make up the count. Of several batteries with
+/// the same joltage, the earliest is chosen.
+///
+/// Panics if the bank has fewer than `count` batteries.
+pub fn select(bank: &str, count: usize) -> Selection<'_> {
+    let digits = bank.as_bytes();
+    assert!(
+        count <= digits.len(),
+        "can't choose {count} batteries from a bank of {}",
+        digits.len()
+    );
+
+    let mut skips = digits.len() - count;
+    let mut positions: Vec<usize> = Vec::with_capacity(digits.len());
+    for (idx, &digit) in digits.iter().enumerate() {
+        while skips > 0 && positions.last().is_some_and(|&last| digits[last] < digit) {
+            positions.pop();
+            skips -= 1;
+        }
+        positions.push(idx);
+    }
+    positions.truncate(count);
+
+    Selection { bank, positions }
+}
+
+/// The batteries chosen from each bank in the input.
+pub fn selections(input: &str, count: usize) -> impl Iterator<Item = Selection<'_>> {
+    input.trim().lines().map(move |line| select(line, count))
+}
+
+/// The batteries chosen from every bank in the input, for inspecting the choices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary<'a> {
+    /// How many batteries were chosen from each bank.
+    pub count: usize,
+    pub selections: Vec<Selection<'a>>,
+}
+
+impl<'a> Summary<'a> {
+    pub fn new(input: &'a str, count: usize) -> Self {
+        Self {
+            count,
+            selections: selections(input, count).collect(),
+        }
+    }
+
+    /// The total joltage, or `None` if it doesn't fit in a `u128`.
+    pub fn total_joltage(&self) -> Option<u128> {
+        self.selections.iter().try_fold(0u128, |sum, selection| {
+            sum.checked_add(selection.joltage()?)
+        })
+    }
+
+    /// The selection with the largest joltage, the first of them if there's a tie.
+    pub fn largest(&self) -> Option<&Selection<'a>> {
+        // selections of the same length compare like their joltages when written out
+        let key = |selection: &Selection<'_>| selection.digits().collect::<Vec<_>>();
+        self.selections
+            .iter()
+            .rev()
+            .max_by_key(|selection| key(selection))
+    }
+
+    /// Each bank with its chosen batteries highlighted and its joltage, followed by the total.
+    pub fn render(&self, highlight: Highlight) -> String {
+        let mut rendered = String::new();
+        for (line, selection) in self.selections.iter().enumerate() {
+            let prefix = format!("{:>4}: ", line + 1);
+            let bank = selection.render(highlight);
+            match bank.split_once('\n') {
+                Some((bank, carets)) => {
+                    let indent = " ".repeat(prefix.len());
+                    rendered += &format!("{prefix}{bank} => {selection}\n{indent}{carets}\n");
+                }
+                None => rendered += &format!("{prefix}{bank} => {selection}\n"),
+            }
+        }
+        match self.total_joltage() {
+            Some(total) => rendered += &format!("total: {total}\n"),
+            None => rendered += "total: too large for a u128\n",
+        }
+        rendered
+    }
+}
+
+fn total_joltage(input: &str, count: usize) -> u128 {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            select(line, count)
+                .joltage()
+                .expect("the joltage fits in a u128")
+        })
+        .sum()
+}
+
+pub fn input_generator(input: &str) -> Result<&str, ParseError> {
+    for line in input.trim().lines() {
+        if let Some(idx) = line.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseError::at(Day03::DAY, input, &line[idx..], "a digit"));
+        }
+        // part 2 turns on 12 batteries in every bank
+        if line.len() < 12 {
+            return Err(ParseError::at(
+                Day03::DAY,
+                input,
+                &line[line.len()..],
+                "a bank of at least 12 batteries",
+            ));
+        }
+    }
+    Ok(input)
+}
+
+pub fn part_1(input: &str) -> u128 {
+    total_joltage(input, 2)
+}
+
+pub fn part_2(input: &str) -> u128 {
+    total_joltage(input, 12)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = indoc! {
+            "
+            987654321111111
+            811111111111119
+            234234234234278
+            818181911112111
+            "
+        };
+        assert_eq!(part_1(input), 357);
+        assert_eq!(part_2(input), 3121910778619);
+
+        let selection = select("818181911112111", 12);
+        assert_eq!(
+            selection.positions(),
+            [0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+        );
+        assert_eq!(selection.to_string(), "888911112111");
+        assert_eq!(selection.joltage(), Some(888911112111));
+
+        // the O(n·k) greedy choice of the largest remaining digit that leaves enough after it
+        let naive = |bank: &str, count: usize| {
+            let mut next = 0;
+            (0..count)
+                .map(|picked| {
+                    let window = &bank[next..bank.len() - (count - picked - 1)];
+                    let largest = window.bytes().max().unwrap();
+                    next += window.bytes().position(|digit| digit == largest).unwrap() + 1;
+                    next - 1
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut rng = Lcg::new(12345);
+        let bank: String = (0..200)
+            .map(|_| char::from(b'0' + rng.below(10) as u8))
+            .collect();
+        for count in [0, 1, 2, 12, 38, 39, 40, 100, 199, 200] {
+            let selection = select(&bank, count);
+            assert_eq!(selection.positions(), naive(&bank, count));
+            assert_eq!(selection.to_string().len(), count);
+            if (1..=38).contains(&count) {
+                assert_eq!(selection.joltage(), selection.to_string().parse().ok());
+            }
+        }
+        assert_eq!(select(&bank, 100).joltage(), None);
+        assert_eq!(select("9".repeat(40).as_str(), 39).joltage(), None);
+
+        let summary = Summary::new(input, 2);
+        assert_eq!(summary.total_joltage(), Some(357));
+        assert_eq!(summary.largest().unwrap().positions(), [0, 1]);
+        assert!(
+            selections(input, 12)
+                .zip(&summary.selections)
+                .all(|(a, b)| a.bank() == b.bank())
+        );
+        assert_eq!(
+            summary.selections[1].render(Highlight::Carets),
+            "811111111111119\n^             ^"
+        );
+        assert_eq!(
+            select("1234", 2).render(Highlight::Ansi),
+            "12\x1b[1;32m3\x1b[0m\x1b[1;32m4\x1b[0m"
+        );
+        assert_eq!(
+            Summary::new("811111111111119\n", 2).render(Highlight::Carets),
+            "   1: 811111111111119 => 89\n      ^             ^\ntotal: 89\n"
+        );
+
+        let err = input_generator("987654321111111\n81111x111111119\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = input_generator("987654321111111\n81111111119\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 12));
+        let err = input_generator("987654321111111\n\n811111111111119\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[cfg(input_exists = "2025/day3")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day03>(
+            2025,
+            include_str!("../../../input/2025/day3.txt"),
+        );
+    }
+}
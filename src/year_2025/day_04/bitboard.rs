@@ -0,0 +1,248 @@
+//! The map as one bit per cell, for counting neighbours a whole word of cells at a time.
+
+use crate::grid::Grid;
+
+/// The occupied cells, with each row packed into `u64`s from its least significant bit and any
+/// bits past the end of the row kept clear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitboard {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+fn full_add(a: u64, b: u64, c: u64) -> (u64, u64) {
+    (a ^ b ^ c, (a & b) | (c & (a ^ b)))
+}
+
+/// The bits of the number of inputs set in each position, least significant first.
+fn count_8(n: [u64; 8]) -> [u64; 4] {
+    let (s1, c1) = full_add(n[0], n[1], n[2]);
+    let (s2, c2) = full_add(n[3], n[4], n[5]);
+    let (s3, c3) = (n[6] ^ n[7], n[6] & n[7]);
+    let (ones, k1) = full_add(s1, s2, s3);
+    let (t, k2) = full_add(c1, c2, c3);
+    let (twos, k3) = (t ^ k1, t & k1);
+    [ones, twos, k2 ^ k3, k2 & k3]
+}
+
+/// The positions where the count is less than `threshold`.
+fn less_than(count: [u64; 4], threshold: usize) -> u64 {
+    if threshold >= 16 {
+        return !0;
+    }
+    // compare from the most significant bit down, while the two are still equal
+    let (mut less, mut equal) = (0, !0);
+    for bit in (0..4).rev() {
+        if threshold >> bit & 1 == 1 {
+            less |= equal & !count[bit];
+            equal &= count[bit];
+        } else {
+            equal &= !count[bit];
+        }
+    }
+    less
+}
+
+/// The cells of `row` moved one column east, so each one holds its western neighbour.
+fn west(row: &[u64], word: usize) -> u64 {
+    let carry = if word > 0 { row[word - 1] >> 63 } else { 0 };
+    row[word] << 1 | carry
+}
+
+/// The cells of `row` moved one column west, so each one holds its eastern neighbour.
+fn east(row: &[u64], word: usize) -> u64 {
+    let carry = row.get(word + 1).map_or(0, |&next| next << 63);
+    row[word] >> 1 | carry
+}
+
+impl Bitboard {
+    pub fn from_grid(grid: &Grid<u8>, is_occupied: impl Fn(u8) -> bool) -> Self {
+        let words_per_row = grid.width().div_ceil(64);
+        let mut bits = vec![0; words_per_row * grid.height()];
+        for (p, &b) in grid.iter() {
+            if is_occupied(b) {
+                bits[p.y * words_per_row + p.x / 64] |= 1 << (p.x % 64);
+            }
+        }
+
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            words_per_row,
+            bits,
+        }
+    }
+
+    /// The map with `occupied` in the occupied cells and `empty` everywhere else.
+    pub fn to_grid(&self, occupied: u8, empty: u8) -> Grid<u8> {
+        let mut grid = Grid::filled(self.width, self.height, empty);
+        for p in grid.points() {
+            if self.row(p.y)[p.x / 64] >> (p.x % 64) & 1 == 1 {
+                grid[p] = occupied;
+            }
+        }
+        grid
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.bits[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// The number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// The occupied cells with fewer than `threshold` occupied orthogonal and diagonal neighbours,
+    /// treating everything past the edges as empty.
+    pub fn accessible(&self, threshold: usize) -> Self {
+        let bits = (0..self.bits.len())
+            .map(|idx| self.accessible_word(idx, threshold))
+            .collect();
+        Self { bits, ..*self }
+    }
+
+    /// The accessible cells in the word at `idx` in `bits`.
+    fn accessible_word(&self, idx: usize, threshold: usize) -> u64 {
+        let (y, word) = (idx / self.words_per_row, idx % self.words_per_row);
+        let shifted = |row: &[u64]| [west(row, word), row[word], east(row, word)];
+        let [nw, n, ne] = if y > 0 {
+            shifted(self.row(y - 1))
+        } else {
+            [0; 3]
+        };
+        let [sw, s, se] = if y + 1 < self.height {
+            shifted(self.row(y + 1))
+        } else {
+            [0; 3]
+        };
+        let row = self.row(y);
+        let count = count_8([nw, n, ne, west(row, word), east(row, word), sw, s, se]);
+        row[word] & less_than(count, threshold)
+    }
+
+    /// Removes the accessible cells a wave at a time until there are none left, returning how
+    /// many were removed.
+    ///
+    /// Only the words next to the cells removed in one wave can change for the next, so only
+    /// those are looked at again, which keeps a long chain of single removals linear.
+    pub fn remove_in_waves(&mut self, threshold: usize) -> usize {
+        let mut words: Vec<_> = (0..self.bits.len()).collect();
+        // the last wave each word was queued for, so it's only queued once
+        let mut queued = vec![0; self.bits.len()];
+        let mut removed = 0;
+        for wave in 1.. {
+            let accessible: Vec<_> = (words.drain(..))
+                .map(|idx| (idx, self.accessible_word(idx, threshold)))
+                .filter(|&(_, bits)| bits != 0)
+                .collect();
+            if accessible.is_empty() {
+                break;
+            }
+
+            for (idx, bits) in accessible {
+                self.bits[idx] &= !bits;
+                removed += bits.count_ones() as usize;
+
+                // the words either side only change if the bit next to them was removed
+                let (y, word) = (idx / self.words_per_row, idx % self.words_per_row);
+                let west = if bits & 1 == 1 {
+                    word.saturating_sub(1)
+                } else {
+                    word
+                };
+                let east = if bits >> 63 == 1 { word + 1 } else { word };
+                for y in y.saturating_sub(1)..(y + 2).min(self.height) {
+                    for word in west..=east.min(self.words_per_row - 1) {
+                        let idx = y * self.words_per_row + word;
+                        if queued[idx] != wave {
+                            queued[idx] = wave;
+                            words.push(idx);
+                        }
+                    }
+                }
+            }
+        }
+        removed
+    }
+
+    /// Clears every cell that's occupied in `other`, which must be the same size.
+    pub fn remove(&mut self, other: &Self) {
+        for (word, &removed) in self.bits.iter_mut().zip(&other.bits) {
+            *word &= !removed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    #[test]
+    fn test() {
+        for n in 0..256u32 {
+            let inputs = std::array::from_fn(|i| if n >> i & 1 == 1 { !0 } else { 0 });
+            let count = count_8(inputs);
+            let bits: u32 = (0..4).map(|bit| (count[bit] & 1) as u32 * (1 << bit)).sum();
+            assert_eq!(bits, n.count_ones());
+            for threshold in 0..=16 {
+                let less = less_than(count, threshold) & 1 == 1;
+                assert_eq!(less, (n.count_ones() as usize) < threshold);
+            }
+        }
+
+        let grid = Grid::from_cells(3, b"@@@@.@@@@".to_vec());
+        let board = Bitboard::from_grid(&grid, |b| b == b'@');
+        assert_eq!(board.len(), 8);
+        assert_eq!(board.to_grid(b'@', b'.'), grid);
+        assert_eq!(
+            board.accessible(4).to_grid(b'@', b'.').to_string(),
+            "@.@\n...\n@.@\n"
+        );
+        assert_eq!(board.accessible(2).len(), 0);
+
+        // across word boundaries, against counting each cell's neighbours
+        let mut rng = Lcg::new(12345);
+        for width in [1, 63, 64, 65, 130] {
+            let cells: Vec<u8> = (0..width * 7)
+                .map(|_| if rng.below(3) < 2 { b'@' } else { b'.' })
+                .collect();
+            let grid = Grid::from_cells(width, cells);
+            let board = Bitboard::from_grid(&grid, |b| b == b'@');
+            for threshold in [0, 3, 4, 9] {
+                let mut expected = grid.clone();
+                for p in grid.points() {
+                    let count = grid.neighbours_8(p).filter(|&n| grid[n] == b'@').count();
+                    if grid[p] == b'@' && count >= threshold {
+                        expected[p] = b'.';
+                    }
+                }
+                assert_eq!(board.accessible(threshold).to_grid(b'@', b'.'), expected);
+
+                // against removing every accessible cell in each wave
+                let (mut expected, mut removed) = (board.clone(), 0);
+                loop {
+                    let wave = expected.accessible(threshold);
+                    if wave.is_empty() {
+                        break;
+                    }
+                    removed += wave.len();
+                    expected.remove(&wave);
+                }
+                let mut remaining = board.clone();
+                assert_eq!(remaining.remove_in_waves(threshold), removed);
+                assert_eq!(remaining, expected);
+            }
+        }
+    }
+}
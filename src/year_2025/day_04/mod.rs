@@ -0,0 +1,417 @@
+mod bitboard;
+
+use std::fmt::Display;
+
+use crate::{
+    Solution,
+    error::ParseError,
+    grid::{Grid, NEIGHBOURS_4, NEIGHBOURS_8, Point},
+};
+
+pub use bitboard::Bitboard;
+
+pub struct Input {
+    grid: Grid<u8>,
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse(Day04::DAY, input, "'@' or '.'", |b| b == b'@' || b == b'.')?;
+    Ok(Input { grid })
+}
+
+/// Which cells around a roll are its neighbours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The eight orthogonal and diagonal neighbours.
+    Moore,
+    /// The four orthogonal neighbours.
+    VonNeumann,
+    /// Any offsets, which are counted as many times as they're given.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Self::Moore => &NEIGHBOURS_8,
+            Self::VonNeumann => &NEIGHBOURS_4,
+            Self::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// What lies beyond the edges of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing, so cells past the edge are never occupied.
+    Bounded,
+    /// The opposite edge.
+    Wrap,
+}
+
+/// When a roll is accessible, which defaults to the puzzle's rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub neighbourhood: Neighbourhood,
+    /// A roll is accessible when fewer than this many of its neighbours are occupied.
+    pub threshold: usize,
+    pub edges: Edges,
+    /// The bytes that are rolls, both for being removed and for counting as a neighbour.
+    pub occupied: Vec<u8>,
+    /// The byte left behind when a roll is removed, which mustn't be one of `occupied`.
+    pub vacated: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            neighbourhood: Neighbourhood::Moore,
+            threshold: 4,
+            edges: Edges::Bounded,
+            occupied: vec![b'@'],
+            vacated: b'.',
+        }
+    }
+}
+
+/// [`Rules`] in a form that's quick to apply.
+struct Engine<'a> {
+    offsets: &'a [(isize, isize)],
+    threshold: usize,
+    edges: Edges,
+    is_occupied: [bool; 256],
+}
+
+impl<'a> Engine<'a> {
+    fn new(rules: &'a Rules) -> Self {
+        let mut is_occupied = [false; 256];
+        for &b in &rules.occupied {
+            is_occupied[b as usize] = true;
+        }
+        assert!(
+            !is_occupied[rules.vacated as usize],
+            "removed rolls can't be left occupied"
+        );
+
+        Self {
+            offsets: rules.neighbourhood.offsets(),
+            threshold: rules.threshold,
+            edges: rules.edges,
+            is_occupied,
+        }
+    }
+
+    fn is_occupied(&self, grid: &Grid<u8>, p: Point) -> bool {
+        self.is_occupied[grid[p] as usize]
+    }
+
+    /// The cells that are neighbours of `p` if `sign` is 1, or that `p` is a neighbour of if it's
+    /// -1.
+    fn neighbours<'g>(
+        &'g self,
+        grid: &'g Grid<u8>,
+        p: Point,
+        sign: isize,
+    ) -> impl Iterator<Item = Point> + 'g {
+        self.offsets.iter().filter_map(move |&(dx, dy)| {
+            let d = (sign * dx, sign * dy);
+            match self.edges {
+                Edges::Bounded => grid.offset(p, d),
+                Edges::Wrap => Some(grid.wrapping_offset(p, d)),
+            }
+        })
+    }
+
+    fn occupied_neighbours(&self, grid: &Grid<u8>, p: Point) -> usize {
+        self.neighbours(grid, p, 1)
+            .filter(|&n| self.is_occupied(grid, n))
+            .count()
+    }
+
+    fn is_accessible(&self, grid: &Grid<u8>, p: Point) -> bool {
+        self.is_occupied(grid, p) && self.occupied_neighbours(grid, p) < self.threshold
+    }
+}
+
+/// The rolls in `grid` that are accessible under `rules`.
+pub fn accessible(grid: &Grid<u8>, rules: &Rules) -> impl Iterator<Item = Point> {
+    let engine = Engine::new(rules);
+    grid.points()
+        .filter(move |&p| engine.is_accessible(grid, p))
+}
+
+/// How the map is stored while counting rolls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// A byte per cell, which works with any [`Rules`].
+    Bytes,
+    /// A [`Bitboard`], which only works with the Moore neighbourhood and bounded edges.
+    Bits,
+}
+
+fn bitboard(grid: &Grid<u8>, rules: &Rules) -> Bitboard {
+    assert!(
+        rules.neighbourhood == Neighbourhood::Moore && rules.edges == Edges::Bounded,
+        "the bitboard only supports the Moore neighbourhood with bounded edges"
+    );
+    Bitboard::from_grid(grid, |b| rules.occupied.contains(&b))
+}
+
+/// The number of rolls in `grid` that are accessible under `rules`.
+pub fn count_accessible(grid: &Grid<u8>, rules: &Rules, backend: Backend) -> usize {
+    match backend {
+        Backend::Bytes => accessible(grid, rules).count(),
+        Backend::Bits => bitboard(grid, rules).accessible(rules.threshold).len(),
+    }
+}
+
+pub fn part_1(input: &Input) -> u32 {
+    count_accessible(&input.grid, &Rules::default(), Backend::Bits) as u32
+}
+
+/// A round of removals, in which every roll that was accessible at the start of it is removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wave {
+    /// The rolls removed, in no particular order.
+    pub removed: Vec<Point>,
+    /// How many rolls are left afterwards.
+    pub remaining: usize,
+}
+
+/// The rounds of removals that leave no accessible rolls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Removal {
+    pub waves: Vec<Wave>,
+    /// The map once no more rolls can be removed.
+    pub grid: Grid<u8>,
+}
+
+impl Removal {
+    pub fn total_removed(&self) -> usize {
+        self.waves.iter().map(|wave| wave.removed.len()).sum()
+    }
+}
+
+/// Removes the rolls that are accessible under `rules` a round at a time until there are none
+/// left.
+///
+/// Removing a roll can only make other rolls more accessible, so each roll's count of occupied
+/// neighbours is worked out once and then decremented, and a roll joins the next wave exactly
+/// when it becomes accessible. This visits every cell a bounded number of times.
+pub fn remove_in_waves(grid: &Grid<u8>, rules: &Rules) -> Removal {
+    let engine = Engine::new(rules);
+    let mut grid = grid.clone();
+    let mut counts = grid.map(|_| 0);
+    let mut remaining = 0;
+    let mut wave = vec![];
+    for p in grid.points() {
+        if engine.is_occupied(&grid, p) {
+            remaining += 1;
+            counts[p] = engine.occupied_neighbours(&grid, p);
+            if counts[p] < engine.threshold {
+                wave.push(p);
+            }
+        }
+    }
+
+    let mut waves = vec![];
+    while !wave.is_empty() {
+        // every roll in the wave is removed before any of the next wave
+        for &p in &wave {
+            grid[p] = rules.vacated;
+        }
+        let mut next = vec![];
+        for &p in &wave {
+            // the rolls that had `p` as a neighbour
+            for n in engine.neighbours(&grid, p, -1) {
+                if engine.is_occupied(&grid, n) {
+                    counts[n] -= 1;
+                    // rolls that were already accessible are in this wave or an earlier one
+                    if counts[n] + 1 == engine.threshold {
+                        next.push(n);
+                    }
+                }
+            }
+        }
+
+        remaining -= wave.len();
+        waves.push(Wave {
+            removed: std::mem::replace(&mut wave, next),
+            remaining,
+        });
+    }
+
+    Removal { waves, grid }
+}
+
+/// The number of rolls in `grid` that can be removed by repeatedly removing the accessible ones
+/// under `rules`.
+pub fn count_removable(grid: &Grid<u8>, rules: &Rules, backend: Backend) -> usize {
+    match backend {
+        Backend::Bytes => remove_in_waves(grid, rules).total_removed(),
+        Backend::Bits => bitboard(grid, rules).remove_in_waves(rules.threshold),
+    }
+}
+
+pub fn part_2(input: &Input) -> u32 {
+    count_removable(&input.grid, &Rules::default(), Backend::Bits) as u32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = input_generator(indoc! {
+            "
+            ..@@.@@@@.
+            @@@.@.@.@@
+            @@@@@.@.@@
+            @.@@@@..@.
+            @@.@@@@.@@
+            .@@@@@@@.@
+            .@.@.@.@@@
+            @.@@@.@@@@
+            .@@@@@@@@.
+            @.@.@@@.@.
+            "
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 13);
+        assert_eq!(part_2(&input), 43);
+
+        let removal = remove_in_waves(&input.grid, &Rules::default());
+        assert_eq!(removal.waves.len(), 9);
+        assert_eq!(removal.waves[0].removed.len(), 13);
+        assert_eq!(removal.waves[0].remaining, 71 - 13);
+        assert_eq!(removal.waves.last().unwrap().remaining, 71 - 43);
+        assert_eq!(
+            removal.grid.iter().filter(|(_, b)| **b == b'@').count(),
+            71 - 43
+        );
+
+        let full = Grid::filled(3, 3, b'@');
+        assert_eq!(accessible(&full, &Rules::default()).count(), 4);
+        let wrap = Rules {
+            edges: Edges::Wrap,
+            ..Rules::default()
+        };
+        assert_eq!(accessible(&full, &wrap).count(), 0);
+
+        // removing the accessible rolls a whole pass at a time gives the same waves
+        let mut rng = Lcg::new(12345);
+        let cells: Vec<u8> = (0..100 * 80)
+            .map(|_| b"@@@@@@##.."[rng.below(10) as usize])
+            .collect();
+        let grid = Grid::from_cells(100, cells);
+        let check = |rules: &Rules| {
+            let removal = remove_in_waves(&grid, rules);
+            let mut expected = grid.clone();
+            for wave in &removal.waves {
+                let mut accessible: Vec<_> = accessible(&expected, rules).collect();
+                let mut removed = wave.removed.clone();
+                removed.sort_unstable_by_key(|p| (p.y, p.x));
+                accessible.sort_unstable_by_key(|p| (p.y, p.x));
+                assert_eq!(removed, accessible);
+                for &p in &accessible {
+                    expected[p] = rules.vacated;
+                }
+                let remaining = expected.iter().filter(|(_, b)| rules.occupied.contains(b));
+                assert_eq!(remaining.count(), wave.remaining);
+            }
+            assert_eq!(removal.grid, expected);
+            assert_eq!(accessible(&expected, rules).count(), 0);
+
+            if rules.neighbourhood == Neighbourhood::Moore && rules.edges == Edges::Bounded {
+                let is_occupied = |b| rules.occupied.contains(&b);
+                let mut board = Bitboard::from_grid(&grid, is_occupied);
+                for wave in &removal.waves {
+                    let accessible = board.accessible(rules.threshold);
+                    assert_eq!(accessible.len(), wave.removed.len());
+                    board.remove(&accessible);
+                }
+                assert_eq!(board, Bitboard::from_grid(&removal.grid, is_occupied));
+                let mut board = Bitboard::from_grid(&grid, is_occupied);
+                assert_eq!(
+                    board.remove_in_waves(rules.threshold),
+                    removal.total_removed()
+                );
+                assert_eq!(board, Bitboard::from_grid(&removal.grid, is_occupied));
+                for backend in [Backend::Bytes, Backend::Bits] {
+                    assert_eq!(
+                        count_accessible(&grid, rules, backend),
+                        removal.waves[0].removed.len()
+                    );
+                    assert_eq!(
+                        count_removable(&grid, rules, backend),
+                        removal.total_removed()
+                    );
+                }
+            }
+        };
+        check(&Rules::default());
+        check(&wrap);
+        check(&Rules {
+            neighbourhood: Neighbourhood::VonNeumann,
+            threshold: 2,
+            ..Rules::default()
+        });
+        check(&Rules {
+            threshold: 6,
+            occupied: b"@#".to_vec(),
+            ..Rules::default()
+        });
+        check(&Rules {
+            neighbourhood: Neighbourhood::Custom(vec![(1, 0), (2, 0), (0, 1), (1, 1), (1, 1)]),
+            threshold: 3,
+            edges: Edges::Wrap,
+            occupied: b"@#".to_vec(),
+            vacated: b' ',
+        });
+
+        // a full strip is only removed from its ends, a few rolls a wave
+        for (width, height) in [(3, 1000), (1000, 3), (200, 3)] {
+            let grid = Grid::filled(width, height, b'@');
+            let rules = Rules::default();
+            let removable = count_removable(&grid, &rules, Backend::Bytes);
+            assert_eq!(removable, width * height);
+            assert_eq!(count_removable(&grid, &rules, Backend::Bits), removable);
+        }
+
+        let err = input_generator("..@\n.@\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[cfg(input_exists = "2025/day4")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day04>(
+            2025,
+            include_str!("../../../input/2025/day4.txt"),
+        );
+    }
+}
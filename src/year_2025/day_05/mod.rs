@@ -0,0 +1,188 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use nom::{
+    Parser,
+    character::complete::*,
+    combinator::{cut, not},
+    error::context,
+    multi::*,
+    sequence::{separated_pair, terminated},
+};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
+    interval_set::IntervalSet,
+    interval_tree::IntervalTree,
+};
+
+pub struct Input {
+    /// The fresh ranges as given, for finding which ones an ingredient is in.
+    ranges: IntervalTree<u64>,
+    fresh: IntervalSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+impl Input {
+    /// Whether the ingredient with this ID is fresh, in O(log n) for n fresh ranges.
+    pub fn is_fresh(&self, id: u64) -> bool {
+        self.fresh.contains(id)
+    }
+
+    /// The fresh ranges that the ingredient with this ID is in, in increasing order, in
+    /// O((k + 1) log n) for k of them.
+    pub fn fresh_ranges(&self, id: u64) -> Vec<&RangeInclusive<u64>> {
+        self.ranges.containing(id)
+    }
+
+    /// The IDs of the available ingredients, in the order they were given.
+    pub fn ingredients(&self) -> &[u64] {
+        &self.ingredients
+    }
+}
+
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    let (input, fresh) = separated_list1(
+        // a blank line ends the list of ranges
+        terminated(line_ending, not(line_ending)),
+        cut(separated_pair(u64, char('-'), u64)).map(|(start, end)| start..=end),
+    )
+    .parse(input)?;
+
+    let (input, _) = context("a blank line", (line_ending, line_ending)).parse(input)?;
+    let (input, ingredients) = separated_list1(line_ending, cut(u64)).parse(input)?;
+
+    Ok((
+        input,
+        Input {
+            fresh: fresh.iter().cloned().collect(),
+            ranges: fresh.into_iter().collect(),
+            ingredients,
+        },
+    ))
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    finish(Day05::DAY, input, parse_input(input.trim_end()))
+}
+
+pub fn part_1(input: &Input) -> u32 {
+    input
+        .ingredients
+        .iter()
+        .filter(|&&ingredient| input.is_fresh(ingredient))
+        .count() as u32
+}
+
+pub fn part_2(input: &Input) -> u128 {
+    // at most 2^64 IDs, which always fits
+    input.fresh.len().unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = input_generator(indoc! {
+            "
+            3-5
+            10-14
+            16-20
+            12-18
+
+            1
+            5
+            8
+            11
+            17
+            32
+            "
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 3);
+        assert_eq!(part_2(&input), 14);
+        assert_eq!(input.fresh_ranges(17), [&(12..=18), &(16..=20)]);
+        assert_eq!(input.fresh_ranges(5), [&(3..=5)]);
+        assert!(input.fresh_ranges(8).is_empty() && !input.is_fresh(8));
+        assert!(input.is_fresh(11) && input.ingredients().len() == 6);
+
+        let input = input_generator(indoc! {
+            "
+            1-3
+            5-9
+            10-12
+            8-11
+            2-6
+
+            1
+            "
+        })
+        .unwrap();
+        assert_eq!(part_2(&input), 12);
+
+        let input = input_generator(indoc! {
+            "
+            1-3
+            5-9
+            10-12
+            8-11
+            1-11
+            11-15
+
+            1
+            "
+        })
+        .unwrap();
+        assert_eq!(part_2(&input), 15);
+
+        let input = input_generator(indoc! {
+            "
+            8-10
+            1-3
+
+            1
+            "
+        })
+        .unwrap();
+        assert_eq!(part_2(&input), 6);
+
+        let input = input_generator("0-18446744073709551615\n5-10\n\n1\n").unwrap();
+        assert_eq!(part_2(&input), 1 << 64);
+
+        let err = input_generator("3-5\n10-14\n\n1\n5x\n").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 2));
+    }
+
+    #[cfg(input_exists = "2025/day5")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day05>(
+            2025,
+            include_str!("../../../input/2025/day5.txt"),
+        );
+    }
+}
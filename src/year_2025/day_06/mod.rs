@@ -0,0 +1,185 @@
+use std::{borrow::Cow, fmt::Display};
+
+use crate::{Solution, error::ParseError};
+
+#[derive(Copy, Clone)]
+enum Op {
+    Plus,
+    Times,
+}
+
+pub struct Input<'a> {
+    worksheet: &'a str,
+    ops: Vec<Op>,
+}
+
+pub fn input_generator(input: &str) -> Result<Input<'_>, ParseError> {
+    let error =
+        |at: &str, expected: Cow<'static, str>| ParseError::at(Day06::DAY, input, at, expected);
+
+    let worksheet = input.trim_end_matches(['\r', '\n']);
+    let mut lines = worksheet.lines();
+    let last_line = lines.next_back().unwrap_or(worksheet);
+    let ops: Vec<Op> = last_line
+        .split_ascii_whitespace()
+        .map(|op| match op {
+            "*" => Ok(Op::Times),
+            "+" => Ok(Op::Plus),
+            _ => Err(error(op, "'*' or '+'".into())),
+        })
+        .collect::<Result<_, _>>()?;
+    if ops.is_empty() {
+        return Err(error(last_line, "a line of operators".into()));
+    }
+    // each problem starts in the column of its operator, so the first one is at the very start
+    if !last_line.starts_with(['*', '+']) {
+        return Err(error(last_line, "'*' or '+'".into()));
+    }
+
+    for line in lines {
+        for (idx, num) in line.split_ascii_whitespace().enumerate() {
+            if idx >= ops.len() {
+                let expected = format!("at most {} numbers per line", ops.len());
+                return Err(error(num, expected.into()));
+            }
+            if num.parse::<u64>().is_err() {
+                return Err(error(num, "a number".into()));
+            }
+        }
+    }
+
+    Ok(Input { worksheet, ops })
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let ops = &input.ops;
+    let mut calcs: Vec<u64> = ops
+        .iter()
+        .map(|op| match op {
+            Op::Plus => 0,
+            Op::Times => 1,
+        })
+        .collect();
+
+    for line in input.worksheet.lines() {
+        if line.starts_with(['*', '+']) {
+            break;
+        }
+        for (idx, num) in line.split_ascii_whitespace().enumerate() {
+            let num: u64 = num.parse().unwrap();
+            calcs[idx] = match ops[idx] {
+                Op::Plus => calcs[idx] + num,
+                Op::Times => calcs[idx] * num,
+            };
+        }
+    }
+
+    calcs.iter().sum()
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let lines: Vec<_> = input
+        .worksheet
+        .lines()
+        .map(|line| line.as_bytes())
+        .collect();
+
+    let mut total = 0;
+    let mut col = 0;
+    for &op in &input.ops {
+        let mut calc = match op {
+            Op::Plus => 0,
+            Op::Times => 1,
+        };
+
+        // find non-empty column
+        'outer: loop {
+            for line in &lines {
+                match line.get(col) {
+                    Some(b' ') => continue,
+                    _ => break 'outer,
+                }
+            }
+            col += 1;
+        }
+
+        loop {
+            let mut num = 0;
+            for line in &lines {
+                if let Some(b @ b'0'..=b'9') = line.get(col) {
+                    num = num * 10 + (b - b'0') as u64;
+                }
+            }
+
+            col += 1;
+            if num == 0 {
+                break;
+            }
+
+            calc = match op {
+                Op::Plus => calc + num,
+                Op::Times => calc * num,
+            };
+        }
+
+        total += calc;
+    }
+
+    total
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = input_generator(indoc! {
+            "
+            123 328  51 64
+             45 64  387 23
+              6 98  215 314
+            *   +   *   +
+            "
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 4277556);
+        assert_eq!(part_2(&input), 3263827);
+
+        let err = input_generator("1 2\n3 4\n* -\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = input_generator("1 2\n3 4\n * +\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[cfg(input_exists = "2025/day6")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day06>(
+            2025,
+            include_str!("../../../input/2025/day6.txt"),
+        );
+    }
+}
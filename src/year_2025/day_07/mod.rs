@@ -0,0 +1,169 @@
+use std::fmt::Display;
+
+use crate::{
+    Solution,
+    error::ParseError,
+    grid::{Grid, Point},
+};
+
+pub struct Input {
+    start: Point,
+    grid: Grid<u8>,
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse(Day07::DAY, input, "'.', '^' or 'S'", |b| {
+        b"S.^".contains(&b)
+    })?;
+    let start = grid
+        .find(&b'S')
+        .filter(|start| start.y == 0)
+        .ok_or_else(|| ParseError::at(Day07::DAY, input, input, "a start position 'S'"))?;
+    // the first 'S' is the start, and there can't be another
+    if let Some((offset, _)) = input.match_indices('S').nth(1) {
+        return Err(ParseError::at_offset(
+            Day07::DAY,
+            input,
+            offset,
+            "'.' or '^'",
+        ));
+    }
+
+    Ok(Input { start, grid })
+}
+
+fn explore(grid: &mut Grid<u8>, mut p: Point) -> u32 {
+    let mut count = 0;
+    while let Some(s) = grid.get_mut(p) {
+        match *s {
+            // already split and counted
+            b'v' => break,
+            // splitter, uncounted
+            b'^' => {
+                *s = b'v';
+                count += 1;
+                if p.x > 0 {
+                    count += explore(grid, Point::new(p.x - 1, p.y + 1));
+                }
+                if p.x + 1 < grid.width() {
+                    count += explore(grid, Point::new(p.x + 1, p.y + 1));
+                }
+                break;
+            }
+            // empty, continue
+            _ => {
+                p.y += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part_1(input: &Input) -> u32 {
+    // we will mark each splitter when we explore it
+    let mut grid = input.grid.clone();
+    explore(&mut grid, Point::new(input.start.x, input.start.y + 1))
+}
+
+// returns num unique paths explored starting from p
+fn explore2(grid: &mut Grid<u64>, mut p: Point) -> u64 {
+    while let Some(&s) = grid.get(p) {
+        if s == b'^' as u64 {
+            // unexplored
+            let mut count = 0;
+            if p.x > 0 {
+                count += explore2(grid, Point::new(p.x - 1, p.y + 1));
+            }
+            if p.x + 1 < grid.width() {
+                count += explore2(grid, Point::new(p.x + 1, p.y + 1));
+            }
+
+            // cache the path count
+            grid[p] = count + b'^' as u64;
+            return count;
+        } else if s > b'^' as u64 {
+            // cached path count
+            return s - b'^' as u64;
+        } else {
+            p.y += 1;
+        }
+    }
+
+    1
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let mut grid = input.grid.map(|&b| b as u64);
+    explore2(&mut grid, Point::new(input.start.x, input.start.y + 1))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = input_generator(indoc! {
+            "
+            .......S.......
+            ...............
+            .......^.......
+            ...............
+            ......^.^......
+            ...............
+            .....^.^.^.....
+            ...............
+            ....^.^...^....
+            ...............
+            ...^.^...^.^...
+            ...............
+            ..^...^.....^..
+            ...............
+            .^.^.^.^.^...^.
+            ...............
+            "
+        })
+        .unwrap();
+        assert_eq!(part_1(&input), 21);
+        assert_eq!(part_2(&input), 40);
+
+        let err = input_generator("..S..\n.....\n..#..\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = input_generator("..S..\n.....\n.S...\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+        let err = input_generator("..SS.\n.....\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+
+    #[cfg(input_exists = "2025/day7")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day07>(
+            2025,
+            include_str!("../../../input/2025/day7.txt"),
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! A static 3D k-d tree for nearest-neighbour queries between the junction boxes.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::{Point3, dist, square};
+
+fn coord(p: &Point3, axis: usize) -> u64 {
+    match axis {
+        0 => p.0,
+        1 => p.1,
+        _ => p.2,
+    }
+}
+
+/// The smallest and largest corners of a box.
+type Bounds = (Point3, Point3);
+
+fn union((min1, max1): Bounds, (min2, max2): Bounds) -> Bounds {
+    (
+        (min1.0.min(min2.0), min1.1.min(min2.1), min1.2.min(min2.2)),
+        (max1.0.max(max2.0), max1.1.max(max2.1), max1.2.max(max2.2)),
+    )
+}
+
+/// The squared distance from `p` to the nearest point in `bounds`.
+fn dist_to_bounds(p: &Point3, (min, max): &Bounds) -> u64 {
+    (0..3)
+        .map(|axis| {
+            let (c, lo, hi) = (coord(p, axis), coord(min, axis), coord(max, axis));
+            square(lo.saturating_sub(c) + c.saturating_sub(hi))
+        })
+        .sum()
+}
+
+/// The points, arranged so that the middle of each subslice splits it on the axis for that depth.
+pub struct KdTree {
+    nodes: Vec<(Point3, u32)>,
+    /// The bounding box of the subtree rooted at each node.
+    bounds: Vec<Bounds>,
+}
+
+impl KdTree {
+    pub fn new(points: &[Point3]) -> Self {
+        let mut nodes: Vec<_> = points.iter().zip(0..).map(|(p, idx)| (*p, idx)).collect();
+        let mut bounds = vec![Bounds::default(); nodes.len()];
+        Self::build(&mut nodes, &mut bounds, 0);
+        Self { nodes, bounds }
+    }
+
+    fn build(nodes: &mut [(Point3, u32)], bounds: &mut [Bounds], depth: usize) -> Option<Bounds> {
+        if nodes.is_empty() {
+            return None;
+        }
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by_key(mid, |(p, _)| coord(p, depth % 3));
+        let (left, right) = nodes.split_at_mut(mid);
+        let (left_bounds, right_bounds) = bounds.split_at_mut(mid);
+        let children = [
+            Self::build(left, left_bounds, depth + 1),
+            Self::build(&mut right[1..], &mut right_bounds[1..], depth + 1),
+        ];
+        let p = right[0].0;
+        right_bounds[0] = children.into_iter().flatten().fold((p, p), union);
+        Some(right_bounds[0])
+    }
+
+    /// A search for the points nearest to `target`, excluding the one at `idx`.
+    pub fn neighbours(&self, target: Point3, idx: u32) -> Neighbours {
+        let mut neighbours = Neighbours {
+            target,
+            skip: idx,
+            queue: BinaryHeap::new(),
+        };
+        neighbours.queue_subtree(self, 0, self.nodes.len());
+        neighbours
+    }
+
+    /// The circuit that every point in each subtree is in, if they're all in the same one, given
+    /// the circuit of each point.
+    pub fn uniform_circuits(&self, circuits: &[u32]) -> Vec<Option<u32>> {
+        let mut uniform = vec![None; self.nodes.len()];
+        self.uniform(circuits, &mut uniform, 0, self.nodes.len());
+        uniform
+    }
+
+    fn uniform(
+        &self,
+        circuits: &[u32],
+        uniform: &mut [Option<u32>],
+        start: usize,
+        end: usize,
+    ) -> Option<Option<u32>> {
+        if start >= end {
+            return None;
+        }
+        let mid = start + (end - start) / 2;
+        let circuit = Some(circuits[self.nodes[mid].1 as usize]);
+        let children = [
+            self.uniform(circuits, uniform, start, mid),
+            self.uniform(circuits, uniform, mid + 1, end),
+        ];
+        uniform[mid] = (children.into_iter().flatten()).fold(circuit, |a, b| a.filter(|_| a == b));
+        Some(uniform[mid])
+    }
+
+    /// Lowers `best` to the closest pair between the point `target` at `idx` and one in another
+    /// circuit, as `(squared distance, index, index)` with the smaller index first, if there's one
+    /// closer.
+    ///
+    /// `uniform` is from [`Self::uniform_circuits`], and lets whole subtrees in the same circuit as
+    /// the point be skipped.
+    pub fn closest_outside(
+        &self,
+        target: Point3,
+        idx: u32,
+        circuits: &[u32],
+        uniform: &[Option<u32>],
+        best: &mut Option<(u64, u32, u32)>,
+    ) {
+        let search = OutsideSearch {
+            tree: self,
+            target,
+            idx,
+            circuits,
+            uniform,
+        };
+        search.search(best, 0, self.nodes.len());
+    }
+}
+
+struct OutsideSearch<'a> {
+    tree: &'a KdTree,
+    target: Point3,
+    idx: u32,
+    circuits: &'a [u32],
+    uniform: &'a [Option<u32>],
+}
+
+impl OutsideSearch<'_> {
+    fn dist_to_subtree(&self, start: usize, end: usize) -> u64 {
+        dist_to_bounds(&self.target, &self.tree.bounds[start + (end - start) / 2])
+    }
+
+    fn search(&self, best: &mut Option<(u64, u32, u32)>, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let circuit = self.circuits[self.idx as usize];
+        if self.uniform[mid] == Some(circuit)
+            || best.is_some_and(|(d, _, _)| self.dist_to_subtree(start, end) > d)
+        {
+            return;
+        }
+
+        let (p, other) = self.tree.nodes[mid];
+        if self.circuits[other as usize] != circuit {
+            let pair = (
+                dist(&self.target, &p),
+                self.idx.min(other),
+                self.idx.max(other),
+            );
+            if best.is_none_or(|best| pair < best) {
+                *best = Some(pair);
+            }
+        }
+
+        // the nearer side first, so the further one is more likely to be skipped
+        let mut children = [(start, mid), (mid + 1, end)];
+        children
+            .sort_by_key(|&(start, end)| (start < end).then(|| self.dist_to_subtree(start, end)));
+        for (start, end) in children {
+            self.search(best, start, end);
+        }
+    }
+}
+
+const SUBTREE: u8 = 0;
+const POINT: u8 = 1;
+
+/// A best-first search through a [`KdTree`] that finds the nearest points one at a time, so it can
+/// be resumed whenever another one is needed.
+///
+/// Only the frontier of the search is kept: the subtrees that haven't been opened yet, and the
+/// points seen that are further away than some of those subtrees might be.
+pub struct Neighbours {
+    target: Point3,
+    skip: u32,
+    /// Subtrees as `(distance to their bounding box, SUBTREE, start, end)` and points as
+    /// `(distance, POINT, index, 0)`, so a subtree is opened before any point that it could hold
+    /// something nearer than.
+    queue: BinaryHeap<Reverse<(u64, u8, u32, u32)>>,
+}
+
+impl Neighbours {
+    fn queue_subtree(&mut self, tree: &KdTree, start: usize, end: usize) {
+        if start < end {
+            let bounds = &tree.bounds[start + (end - start) / 2];
+            let d = dist_to_bounds(&self.target, bounds);
+            self.queue
+                .push(Reverse((d, SUBTREE, start as u32, end as u32)));
+        }
+    }
+
+    /// The next nearest point as `(squared distance, index)`, with ties broken by index.
+    pub fn next(&mut self, tree: &KdTree) -> Option<(u64, u32)> {
+        while let Some(Reverse((d, kind, a, b))) = self.queue.pop() {
+            if kind == POINT {
+                return Some((d, a));
+            }
+
+            let (start, end) = (a as usize, b as usize);
+            let mid = start + (end - start) / 2;
+            let (p, idx) = &tree.nodes[mid];
+            if *idx != self.skip {
+                self.queue
+                    .push(Reverse((dist(&self.target, p), POINT, *idx, 0)));
+            }
+            self.queue_subtree(tree, start, mid);
+            self.queue_subtree(tree, mid + 1, end);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    #[test]
+    fn test() {
+        let mut rng = Lcg::new(12345);
+        let points: Vec<Point3> = (0..200)
+            .map(|_| (rng.below(50), rng.below(50), rng.below(50)))
+            .collect();
+        let tree = KdTree::new(&points);
+        let empty = KdTree::new(&[]);
+        assert_eq!(empty.neighbours((0, 0, 0), 0).next(&empty), None);
+
+        for (idx, target) in points.iter().enumerate().step_by(7) {
+            let mut expected: Vec<_> = (0..points.len() as u32)
+                .filter(|&j| j as usize != idx)
+                .map(|j| (dist(target, &points[j as usize]), j))
+                .collect();
+            expected.sort_unstable();
+            let mut neighbours = tree.neighbours(*target, idx as u32);
+            let nearest: Vec<_> = std::iter::from_fn(|| neighbours.next(&tree)).collect();
+            assert_eq!(nearest, expected);
+        }
+    }
+}
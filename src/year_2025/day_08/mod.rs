@@ -0,0 +1,265 @@
+mod kd_tree;
+
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display};
+
+use nom::{Parser, character::complete::*, combinator::cut, multi::*, sequence::preceded};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
+    union_find::UnionFind,
+};
+
+use kd_tree::{KdTree, Neighbours};
+
+pub type Point3 = (u64, u64, u64);
+pub type Input = Vec<Point3>;
+
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    separated_list1(
+        line_ending,
+        cut((u64, preceded(char(','), u64), preceded(char(','), u64))),
+    )
+    .parse(input)
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    let trimmed = input.trim_end();
+    let boxes = finish(Day08::DAY, input, parse_input(trimmed))?;
+    // part 2 connects the boxes into a single circuit, which takes at least a pair of them
+    if boxes.len() < 2 {
+        return Err(ParseError::at(
+            Day08::DAY,
+            input,
+            &trimmed[trimmed.len()..],
+            "a second junction box",
+        ));
+    }
+    Ok(boxes)
+}
+
+fn square(n: u64) -> u64 {
+    n * n
+}
+
+fn dist(p1: &Point3, p2: &Point3) -> u64 {
+    square(p1.0.abs_diff(p2.0)) + square(p1.1.abs_diff(p2.1)) + square(p1.2.abs_diff(p2.2))
+}
+
+/// Every pair of junction boxes in increasing order of distance, as `(squared distance, index,
+/// index)` with the smaller index first and ties broken by index.
+///
+/// Rather than sorting all n² pairs, this merges the streams of each box's nearest neighbours,
+/// which are searched for only as they're needed.
+pub struct ClosestPairs {
+    tree: KdTree,
+    /// The search for the next nearest neighbour of each box.
+    searches: Vec<Neighbours>,
+    /// The next pair from each box, tagged with that box so its stream can be advanced.
+    queue: BinaryHeap<Reverse<(u64, u32, u32, u32)>>,
+    last: Option<(u64, u32, u32)>,
+}
+
+impl ClosestPairs {
+    pub fn new(points: &[Point3]) -> Self {
+        let tree = KdTree::new(points);
+        let searches = (points.iter().zip(0..))
+            .map(|(p, idx)| tree.neighbours(*p, idx))
+            .collect();
+        let mut pairs = Self {
+            tree,
+            searches,
+            queue: BinaryHeap::with_capacity(points.len()),
+            last: None,
+        };
+        for idx in 0..points.len() {
+            pairs.queue_next(idx);
+        }
+        pairs
+    }
+
+    /// Queues the next nearest neighbour of `idx`, if it has any left.
+    fn queue_next(&mut self, idx: usize) {
+        if let Some((d, other)) = self.searches[idx].next(&self.tree) {
+            let idx = idx as u32;
+            self.queue
+                .push(Reverse((d, idx.min(other), idx.max(other), idx)));
+        }
+    }
+}
+
+impl Iterator for ClosestPairs {
+    type Item = (u64, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((d, i, j, from)) = self.queue.pop()?;
+            self.queue_next(from as usize);
+            // each pair comes from both of its boxes, one straight after the other
+            if self.last.replace((d, i, j)) != Some((d, i, j)) {
+                return Some((d, i as usize, j as usize));
+            }
+        }
+    }
+}
+
+/// Connects the `limit` closest pairs and multiplies the sizes of the three largest circuits.
+pub fn solve1(input: &Input, limit: usize) -> u64 {
+    let mut circuits = UnionFind::new(input.len());
+    for (_, i, j) in ClosestPairs::new(input).take(limit) {
+        circuits.union(i, j);
+    }
+
+    let mut sizes: Vec<_> = circuits.component_sizes().collect();
+    sizes.sort_unstable_by_key(|&size| Reverse(size));
+    sizes.iter().take(3).map(|&size| size as u64).product()
+}
+
+/// Connects the closest pairs until there's a single circuit, returning the product of the X
+/// coordinates of the last pair connected.
+///
+/// Going through the pairs in order would mean going through nearly all of them when the boxes
+/// are in far apart clusters, so this builds the same spanning tree with Borůvka's algorithm
+/// instead: each round connects every circuit to the closest box outside it. Ties are broken
+/// like [`ClosestPairs`] breaks them, so the last pair connected in order is the furthest apart.
+pub fn solve2(input: &Input) -> u64 {
+    assert!(
+        input.len() >= 2,
+        "there must be at least two junction boxes"
+    );
+
+    let tree = KdTree::new(input);
+    let mut circuits = UnionFind::new(input.len());
+    let mut last = None;
+    while circuits.components() > 1 {
+        let circuit: Vec<_> = (0..input.len())
+            .map(|idx| circuits.find(idx) as u32)
+            .collect();
+        let uniform = tree.uniform_circuits(&circuit);
+
+        let mut closest = vec![None; input.len()];
+        for (p, idx) in input.iter().zip(0..) {
+            let best = &mut closest[circuit[idx as usize] as usize];
+            tree.closest_outside(*p, idx, &circuit, &uniform, best);
+        }
+        for (d, i, j) in closest.into_iter().flatten() {
+            if circuits.union(i as usize, j as usize) {
+                last = last.max(Some((d, i as usize, j as usize)));
+            }
+        }
+    }
+
+    let (_, i, j) = last.unwrap();
+    input[i].0 * input[j].0
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    solve1(input, 1000)
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    solve2(input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+    use indoc::indoc;
+
+    #[test]
+    fn test() {
+        let input = input_generator(indoc! {
+            "
+            162,817,812
+            57,618,57
+            906,360,560
+            592,479,940
+            352,342,300
+            466,668,158
+            542,29,236
+            431,825,988
+            739,650,466
+            52,470,668
+            216,146,977
+            819,987,18
+            117,168,530
+            805,96,715
+            346,949,466
+            970,615,88
+            941,993,340
+            862,61,35
+            984,92,344
+            425,690,689
+            "
+        })
+        .unwrap();
+        assert_eq!(solve1(&input, 10), 40);
+        assert_eq!(part_2(&input), 25272);
+
+        // against sorting every pair
+        let check = |points: &Input| {
+            let mut pairs: Vec<_> = (0..points.len())
+                .flat_map(|i| (i + 1..points.len()).map(move |j| (i, j)))
+                .map(|(i, j)| (dist(&points[i], &points[j]), i, j))
+                .collect();
+            pairs.sort_unstable();
+            assert!(ClosestPairs::new(points).eq(pairs.iter().copied()));
+
+            let mut circuits = UnionFind::new(points.len());
+            let (_, i, j) = (pairs.into_iter())
+                .filter(|&(_, i, j)| circuits.union(i, j))
+                .last()
+                .unwrap();
+            assert_eq!(solve2(points), points[i].0 * points[j].0);
+        };
+        check(&input);
+
+        // two far apart clusters, so every box runs through most of its neighbours before
+        // reaching the other cluster
+        let mut rng = Lcg::new(12345);
+        let clustered: Input = (0..300)
+            .map(|i| {
+                let offset = if i % 2 == 0 { 0 } else { 1_000_000 };
+                let mut coord = || offset + rng.below(100);
+                (coord(), coord(), coord())
+            })
+            .collect();
+        check(&clustered);
+
+        let err = input_generator("1,2,3\n4,5\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = input_generator("1,1,1\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+
+    #[cfg(input_exists = "2025/day8")]
+    #[test]
+    fn test_my_input() {
+        crate::answers::assert_my_answers::<Day08>(
+            2025,
+            include_str!("../../../input/2025/day8.txt"),
+        );
+    }
+}
@@ -1,18 +1,24 @@
-use std::ops::RangeInclusive;
+use std::{fmt::Display, ops::RangeInclusive};
+
+use nom::{Parser, character::complete::*, combinator::cut, multi::*, sequence::separated_pair};
+
+use crate::{
+    Solution,
+    error::{IResult, ParseError, finish},
+};
 
 pub struct Input {
     tiles: Vec<(u32, u32)>,
 }
 
-pub fn input_generator(input: &str) -> Input {
-    let tiles = input
-        .lines()
-        .map(|line| {
-            let (left, right) = line.split_once(',').unwrap();
-            (left.parse().unwrap(), right.parse().unwrap())
-        })
-        .collect();
-    Input { tiles }
+fn parse_input(input: &str) -> IResult<'_, Input> {
+    let (input, tiles) =
+        separated_list1(line_ending, cut(separated_pair(u32, char(','), u32))).parse(input)?;
+    Ok((input, Input { tiles }))
+}
+
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    finish(Day09::DAY, input, parse_input(input.trim_end()))
 }
 
 fn rects(input: &Input) -> impl Iterator<Item = (&(u32, u32), &(u32, u32))> {
@@ -121,6 +127,27 @@ pub fn part_2(input: &Input) -> u64 {
     max_area
 }
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Movie Theater";
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input_generator(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,16 +166,21 @@ mod tests {
             2,3
             7,3
             "
-        });
+        })
+        .unwrap();
         assert_eq!(part_1(&input), 50);
         assert_eq!(part_2(&input), 24);
+
+        let err = input_generator("7,1\n11;1\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
     }
 
-    #[cfg(input_exists)]
+    #[cfg(input_exists = "2025/day9")]
     #[test]
     fn test_my_input() {
-        let input = input_generator(include_str!("../../input/2025/day9.txt"));
-        assert_eq!(part_1(&input), 4759420470);
-        assert_eq!(part_2(&input), 1603439684);
+        crate::answers::assert_my_answers::<Day09>(
+            2025,
+            include_str!("../../../input/2025/day9.txt"),
+        );
     }
 }